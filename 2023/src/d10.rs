@@ -1,7 +1,6 @@
-use crate::common::Point2D;
-use crate::DayTask;
+use crate::common::{geometry::Polygon, Path, Point2D};
+use crate::{DayTask, Result};
 use core::panic;
-use std::collections::HashMap;
 
 pub struct Task;
 
@@ -16,64 +15,30 @@ impl DayTask<i32> for Task {
         10
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part1_test_result(&self) -> i32 {
-        8
+    fn get_part1_test_result(&self) -> Vec<i32> {
+        vec![8]
     }
 
-    fn get_part2_test_result(&self) -> i32 {
-        1
+    fn get_part2_test_result(&self) -> Vec<i32> {
+        vec![1]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i32 {
-        get_path(lines).len() as i32 / 2
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i32> {
+        Ok(get_path(lines).len() as i32 / 2)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i32 {
-        let path = get_path(lines);
-        let mut in_counter = 0;
-
-        for y in 0..lines.len() {
-            let mut wall_count = 0;
-            let mut horizontal_start = None;
-            for x in 0..lines[y].len() {
-                let p = Point2D::new(x as isize, y as isize);
-                if path.contains_key(&p) {
-                    let path_element = path.get(&p).unwrap();
-                    match path_element {
-                        'F' | 'L' => {
-                            horizontal_start = Some(path_element);
-                        }
-                        'J' => {
-                            if *horizontal_start.unwrap() == 'F' {
-                                wall_count += 1;
-                            }
-                        }
-                        '7' => {
-                            if *horizontal_start.unwrap() == 'L' {
-                                wall_count += 1;
-                            }
-                        }
-                        '-' => {}
-                        '|' => {
-                            wall_count += 1;
-                        }
-                        _ => panic!("Invalid character"),
-                    }
-                } else if wall_count % 2 == 1 {
-                    in_counter += 1;
-                }
-            }
-        }
-
-        in_counter
+    // the loop goes through the middle of its tiles, so the enclosed tiles are the lattice
+    // points strictly inside of it
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i32> {
+        Ok(Polygon::new(get_path(lines)).interior_points() as i32)
     }
 
     fn get_part1_result(&self) -> Option<i32> {
@@ -85,7 +50,8 @@ impl DayTask<i32> for Task {
     }
 }
 
-fn get_path(lines: &Vec<String>) -> HashMap<Point2D<isize>, char> {
+// the tiles of the loop, in the order they're walked
+fn get_path(lines: &Vec<String>) -> Path<isize> {
     let mut s = Point2D::new(0, 0);
     for li in 0..lines.len() {
         let l = &lines[li];
@@ -95,14 +61,16 @@ fn get_path(lines: &Vec<String>) -> HashMap<Point2D<isize>, char> {
         }
     }
     let mut current = Point2D::new(s.x + 1, s.y);
-    let mut prev = s.clone();
+    let mut prev = s;
     // I checked and in all cases 'S' is 'F'
-    let mut path: HashMap<Point2D<isize>, char> = HashMap::new();
-    path.insert(s, 'F');
+    let mut path = vec![s];
 
     loop {
         // assuming going clockwise
-        let current_symbol = lines[current.y as usize].chars().nth(current.x as usize).unwrap();
+        let current_symbol = lines[current.y as usize]
+            .chars()
+            .nth(current.x as usize)
+            .unwrap();
         let next_pos = match current_symbol {
             'F' => {
                 // we come from south
@@ -178,7 +146,7 @@ fn get_path(lines: &Vec<String>) -> HashMap<Point2D<isize>, char> {
             }
             _ => panic!("Invalid character"),
         };
-        path.insert(current.clone(), current_symbol);
+        path.push(current);
         prev = current;
         current = next_pos;
 
@@ -1,6 +1,5 @@
-use crate::DayTask;
-use std::{collections::HashMap, io::Lines, usize};
 use crate::common::Point2D;
+use crate::{DayTask, Result};
 
 pub struct Task;
 
@@ -16,33 +15,32 @@ const TI: &str = "...#......
 #...#.....";
 
 impl DayTask<i64> for Task {
-
     fn day_no(&self) -> u8 {
         11
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part1_test_result(&self) -> i64 {
-        374
+    fn get_part1_test_result(&self) -> Vec<i64> {
+        vec![374]
     }
 
-    fn get_part2_test_result(&self) -> i64 {
-        82000210
+    fn get_part2_test_result(&self) -> Vec<i64> {
+        vec![82000210]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
-        run(lines, 2)
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        Ok(run(lines, 2))
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
-        run(lines, 1000000)
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        Ok(run(lines, 1000000))
     }
 
     fn get_part1_result(&self) -> Option<i64> {
@@ -63,21 +61,26 @@ fn run(lines: &Vec<String>, factor: isize) -> i64 {
 
     for source_index in 0..(expanded_star_map.len() - 1) {
         for dest_index in (source_index + 1)..expanded_star_map.len() {
-            total_dist += expanded_star_map[source_index].manhattan_distance(&expanded_star_map[dest_index]);
+            total_dist +=
+                expanded_star_map[source_index].manhattan_distance(&expanded_star_map[dest_index]);
         }
     }
 
     total_dist as i64
 }
 
-fn expand(star_map: &[Point2D<isize>], 
-    rows: Vec<usize>, 
+fn expand(
+    star_map: &[Point2D<isize>],
+    rows: Vec<usize>,
     cols: Vec<usize>,
-    factor: isize) -> Vec<Point2D<isize>> {
+    factor: isize,
+) -> Vec<Point2D<isize>> {
     let mut expanded = Vec::new();
     for p in star_map {
-        let new_x = cols.iter().filter(|c| (**c as isize) < p.x).count() as isize * (factor - 1) + p.x;
-        let new_y = rows.iter().filter(|c| (**c as isize) < p.y).count() as isize * (factor - 1) + p.y;
+        let new_x =
+            cols.iter().filter(|c| (**c as isize) < p.x).count() as isize * (factor - 1) + p.x;
+        let new_y =
+            rows.iter().filter(|c| (**c as isize) < p.y).count() as isize * (factor - 1) + p.y;
         expanded.push(Point2D::new(new_x, new_y));
     }
 
@@ -118,7 +121,7 @@ fn get_empty(lines: &Vec<String>) -> (Vec<usize>, Vec<usize>) {
         if all_empty {
             cols.push(ci);
         }
-    } 
+    }
 
     (rows, cols)
-}
\ No newline at end of file
+}
@@ -1,6 +1,6 @@
 use memoize::memoize;
 
-use crate::DayTask;
+use crate::{DayTask, Result};
 
 pub struct Task;
 
@@ -16,26 +16,26 @@ impl DayTask<i64> for Task {
         12
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
     // 540 ms
-    fn get_part1_test_result(&self) -> i64 {
-        21
+    fn get_part1_test_result(&self) -> Vec<i64> {
+        vec![21]
     }
 
     // 50300 ms
-    fn get_part2_test_result(&self) -> i64 {
-        525152
+    fn get_part2_test_result(&self) -> Vec<i64> {
+        vec![525152]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
-        lines
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        Ok(lines
             .iter()
             .map(|line| {
                 let (chars_str, counts_str) = line.split_once(" ").unwrap();
@@ -46,10 +46,10 @@ impl DayTask<i64> for Task {
                 let chars: String = String::from(chars_str);
                 count_recursive(chars, expected_counts)
             })
-            .sum::<usize>() as i64
+            .sum::<usize>() as i64)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let result: Vec<String> = lines
             .iter()
             .map(|l| {
@@ -62,7 +62,7 @@ impl DayTask<i64> for Task {
                 res
             })
             .collect();
-        result
+        Ok(result
             .iter()
             .map(|line| {
                 let (chars_str, counts_str) = line.split_once(" ").unwrap();
@@ -73,7 +73,7 @@ impl DayTask<i64> for Task {
                 let chars: String = String::from(chars_str);
                 count_recursive(chars, expected_counts)
             })
-            .sum::<usize>() as i64
+            .sum::<usize>() as i64)
     }
 
     fn get_part1_result(&self) -> Option<i64> {
@@ -88,11 +88,11 @@ impl DayTask<i64> for Task {
 #[memoize]
 fn count_recursive(line: String, groups: Vec<u8>) -> usize {
     // case: nothing left in "line", so it's OK only if "groups" is empty
-    if line.len() == 0 {
-        return if groups.len() == 0 { 1 } else { 0 };
+    if line.is_empty() {
+        return if groups.is_empty() { 1 } else { 0 };
     }
     // case: nothing left in "groups", so it's OK only if "line" has no "#"
-    if groups.len() == 0 {
+    if groups.is_empty() {
         return if line.contains("#") { 0 } else { 1 };
     }
     // check first character
@@ -1,5 +1,5 @@
 use crate::common::transpose;
-use crate::DayTask;
+use crate::{DayTask, Result};
 
 pub struct Task;
 
@@ -19,7 +19,7 @@ const TI: &str = "#.##..##.
 ..##..###
 #....#..#";
 
-#[derive(Clone,Debug)]
+#[derive(Clone, Debug)]
 struct IgnoreInfo {
     index: usize,
     transposed: bool,
@@ -30,40 +30,52 @@ impl DayTask<i64> for Task {
         13
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part1_test_result(&self) -> i64 {
-        405
+    fn get_part1_test_result(&self) -> Vec<i64> {
+        vec![405]
     }
 
-    fn get_part2_test_result(&self) -> i64 {
-        400
+    fn get_part2_test_result(&self) -> Vec<i64> {
+        vec![400]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let maps = split_maps(lines);
         let res: usize = maps
             .iter()
             .map(|map| check_both(map, None, false).unwrap())
-            .map(|ii| if ii.transposed { ii.index * 100 } else { ii.index })
+            .map(|ii| {
+                if ii.transposed {
+                    ii.index * 100
+                } else {
+                    ii.index
+                }
+            })
             .sum();
-        res as i64
+        Ok(res as i64)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let maps = split_maps(lines);
         let res: usize = maps
             .iter()
             .map(|map| check_smudge(map).unwrap())
-            .map(|ii| if ii.transposed { ii.index * 100 } else { ii.index })
+            .map(|ii| {
+                if ii.transposed {
+                    ii.index * 100
+                } else {
+                    ii.index
+                }
+            })
             .sum();
-        res as i64
+        Ok(res as i64)
     }
 
     fn get_part1_result(&self) -> Option<i64> {
@@ -80,14 +92,18 @@ fn check_smudge(map: &[String]) -> Option<IgnoreInfo> {
     if first.is_none() {
         panic!("No solution found for original map");
     }
-    let res = check_both(map, first, true);
-    res
+
+    check_both(map, first, true)
 }
 
-fn check_both(map: &[String], to_ignore: Option<IgnoreInfo>, allow_one_off: bool) -> Option<IgnoreInfo> {
+fn check_both(
+    map: &[String],
+    to_ignore: Option<IgnoreInfo>,
+    allow_one_off: bool,
+) -> Option<IgnoreInfo> {
     let ignore = if to_ignore.is_some() {
         let ti = to_ignore.clone().unwrap();
-        if ti.transposed == false {
+        if !ti.transposed {
             Some(ti.index)
         } else {
             None
@@ -96,9 +112,9 @@ fn check_both(map: &[String], to_ignore: Option<IgnoreInfo>, allow_one_off: bool
         None
     };
     let mut res = find_reflection(map, ignore, allow_one_off);
-    if res.is_some() {
+    if let Some(index) = res {
         return Some(IgnoreInfo {
-            index: res.unwrap(),
+            index,
             transposed: false,
         });
     }
@@ -113,7 +129,7 @@ fn check_both(map: &[String], to_ignore: Option<IgnoreInfo>, allow_one_off: bool
         .collect::<Vec<String>>();
     let ignore = if to_ignore.is_some() {
         let ti = to_ignore.clone().unwrap();
-        if ti.transposed == true {
+        if ti.transposed {
             Some(ti.index)
         } else {
             None
@@ -122,9 +138,7 @@ fn check_both(map: &[String], to_ignore: Option<IgnoreInfo>, allow_one_off: bool
         None
     };
     res = find_reflection(&ts, ignore, allow_one_off);
-    if res.is_none() {
-        return None;
-    }
+    res?;
     Some(IgnoreInfo {
         index: res.unwrap(),
         transposed: true,
@@ -1,8 +1,7 @@
-use std::collections::HashMap;
-
 use num::range;
 
-use crate::DayTask;
+use crate::common::cycle::state_at;
+use crate::{DayTask, Result};
 
 pub struct Task;
 
@@ -22,58 +21,43 @@ impl DayTask<i64> for Task {
         14
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part1_test_result(&self) -> i64 {
-        136
+    fn get_part1_test_result(&self) -> Vec<i64> {
+        vec![136]
     }
 
-    fn get_part2_test_result(&self) -> i64 {
-        64
+    fn get_part2_test_result(&self) -> Vec<i64> {
+        vec![64]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let mut map = lines
             .iter()
             .map(|l| l.chars().collect::<Vec<char>>())
             .collect::<Vec<Vec<char>>>();
         roll_north(&mut map);
-        count_weight(&map)
+        Ok(count_weight(&map))
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
-        let mut rounds = 1000000000;
-        // let mut rounds = 20;
-        let mut cache: HashMap<String, usize> = HashMap::new();
-        let mut map = lines
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        let map = lines
             .iter()
             .map(|l| l.chars().collect::<Vec<char>>())
             .collect::<Vec<Vec<char>>>();
-        let cycle_start: usize;
-        let cycle_end: usize;
-        loop {
-            roll_all(&mut map);
-            rounds -= 1;
-            let state = format_map(&map);
-            if cache.contains_key(&state) {
-                cycle_start = cache[&state];
-                cycle_end = rounds;
-                break;
-            }
-            cache.insert(state, rounds);
-        }
-        let cycle_len = cycle_start - cycle_end;
-        rounds = rounds % cycle_len;
-        for _ in 0..rounds {
+        let spin = |map: &Vec<Vec<char>>| {
+            let mut map = map.clone();
             roll_all(&mut map);
-        }
-        count_weight(&map)
+            map
+        };
+        let map = state_at(map, spin, 1000000000);
+        Ok(count_weight(&map))
     }
 
     fn get_part1_result(&self) -> Option<i64> {
@@ -85,6 +69,7 @@ impl DayTask<i64> for Task {
     }
 }
 
+#[allow(dead_code)]
 fn print_map(map: &Vec<Vec<char>>) {
     println!("{}", format_map(map));
 }
@@ -112,7 +97,7 @@ fn roll_north(map: &mut Vec<Vec<char>>) {
 
 fn roll_south(map: &mut Vec<Vec<char>>) {
     let rng = range(0, map.len()).rev();
-    roll_them_vertical(map, rng, map.len() - 1, |y| if y == 0 {0} else {y - 1});
+    roll_them_vertical(map, rng, map.len() - 1, |y| if y == 0 { 0 } else { y - 1 });
 }
 
 fn roll_west(map: &mut Vec<Vec<char>>) {
@@ -122,24 +107,27 @@ fn roll_west(map: &mut Vec<Vec<char>>) {
 
 fn roll_east(map: &mut Vec<Vec<char>>) {
     let rng = range(0, map[0].len()).rev();
-    roll_them_horizontal(map, rng, map[0].len() - 1, |x| if x == 0 {0} else {x - 1});
+    roll_them_horizontal(
+        map,
+        rng,
+        map[0].len() - 1,
+        |x| if x == 0 { 0 } else { x - 1 },
+    );
 }
 
-fn roll_them_vertical<T: Iterator<Item = usize>>(
+fn roll_them_vertical<T: Iterator<Item = usize> + Clone>(
     map: &mut Vec<Vec<char>>,
     range: T,
     start: usize,
     update_fun: impl Fn(usize) -> usize,
-) where
-    T: Clone,
-{
+) {
     for x in 0..map[0].len() {
         let mut first_free_y = start;
         for y in range.clone() {
             if map[y][x] == '#' {
                 // if we are at the top going from the bottom, we need to stop to avoid first_free_y to be -1
                 // if start > 0 && y == 0 {
-                    // break;
+                // break;
                 // }
                 first_free_y = update_fun(y);
                 continue;
@@ -158,14 +146,12 @@ fn roll_them_vertical<T: Iterator<Item = usize>>(
     }
 }
 
-fn roll_them_horizontal<T: Iterator<Item = usize>>(
+fn roll_them_horizontal<T: Iterator<Item = usize> + Clone>(
     map: &mut Vec<Vec<char>>,
     range: T,
     start: usize,
     update_fun: impl Fn(usize) -> usize,
-) where
-    T: Clone,
-{
+) {
     for y in 0..map.len() {
         let mut first_free_x = start;
         for x in range.clone() {
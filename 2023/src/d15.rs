@@ -1,4 +1,4 @@
-use crate::DayTask;
+use crate::{DayTask, Result};
 
 pub struct Task;
 
@@ -16,56 +16,53 @@ enum Op {
 }
 
 impl DayTask<i64> for Task {
-
     fn day_no(&self) -> u8 {
         15
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part1_test_result(&self) -> i64 {
-        1320
+    fn get_part1_test_result(&self) -> Vec<i64> {
+        vec![1320]
     }
 
-    fn get_part2_test_result(&self) -> i64 {
-        145
+    fn get_part2_test_result(&self) -> Vec<i64> {
+        vec![145]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
-        let res: usize = lines[0]
-            .split(",")
-            .map(|s| get_hash(s))
-            .sum();
-        res as i64
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        let res: usize = lines[0].split(",").map(get_hash).sum();
+        Ok(res as i64)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let instructions: Vec<&str> = lines[0].split(",").collect();
         let mut boxes = vec![Vec::<Lens>::new(); 256];
 
         for inst in instructions {
             let parts = inst.split(['=', '-']).collect::<Vec<&str>>();
             let label = parts[0];
-            let op = if parts[1] == "" { Op::Remove } else { Op::Add };
+            let op = if parts[1].is_empty() {
+                Op::Remove
+            } else {
+                Op::Add
+            };
             let box_index = get_hash(label);
             match op {
                 Op::Add => {
                     if let Some(pos) = boxes[box_index].iter().position(|l| l.label == label) {
                         boxes[box_index][pos].power = parts[1].parse::<u8>().unwrap();
-                    }
-                    else {
-                        boxes[box_index].push(
-                            Lens {
-                                label: label.to_string(),
-                                power: parts[1].parse::<u8>().unwrap(),
-                            }
-                        );
+                    } else {
+                        boxes[box_index].push(Lens {
+                            label: label.to_string(),
+                            power: parts[1].parse::<u8>().unwrap(),
+                        });
                     }
                 }
                 Op::Remove => {
@@ -80,14 +77,14 @@ impl DayTask<i64> for Task {
             .enumerate()
             .map(|(box_index, b)| {
                 let box_pow = b
-                .iter()
-                .enumerate()
-                .map(|(lens_index, l)| (box_index + 1)*(lens_index +1)*l.power as usize)
-                .sum::<usize>();
+                    .iter()
+                    .enumerate()
+                    .map(|(lens_index, l)| (box_index + 1) * (lens_index + 1) * l.power as usize)
+                    .sum::<usize>();
                 box_pow
             })
             .sum::<usize>();
-        focus_pow as i64
+        Ok(focus_pow as i64)
     }
 
     fn get_part1_result(&self) -> Option<i64> {
@@ -1,4 +1,4 @@
-use crate::{common::Direction, common::Point2D, DayTask};
+use crate::{common::Direction, common::Point2D, DayTask, Result};
 
 pub struct Task;
 
@@ -29,34 +29,34 @@ impl DayTask<i64> for Task {
         16
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part1_test_result(&self) -> i64 {
-        46
+    fn get_part1_test_result(&self) -> Vec<i64> {
+        vec![46]
     }
 
-    fn get_part2_test_result(&self) -> i64 {
-        51
+    fn get_part2_test_result(&self) -> Vec<i64> {
+        vec![51]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let map = create_map(lines);
-        run_one(
+        Ok(run_one(
             map,
             Beam {
                 pos: Point2D::new(0, 0),
                 dir: Direction::East,
             },
-        )
+        ))
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let map = create_map(lines);
         let mut best_score = 0;
 
@@ -96,7 +96,7 @@ impl DayTask<i64> for Task {
                 }
             }
         }
-        best_score
+        Ok(best_score)
     }
 
     fn get_part1_result(&self) -> Option<i64> {
@@ -154,10 +154,7 @@ fn run_one(mut map: Vec<Vec<MapPosition>>, start: Beam) -> i64 {
                 Direction::West => Point2D::new(pos.x - 1, pos.y),
             };
             if pos_in_map(&next_pos, &map) {
-                beams.push(Beam {
-                    pos: next_pos,
-                    dir: dir,
-                });
+                beams.push(Beam { pos: next_pos, dir });
             }
             continue;
         }
@@ -250,7 +247,7 @@ fn run_one(mut map: Vec<Vec<MapPosition>>, start: Beam) -> i64 {
 
     let res: usize = map
         .iter()
-        .map(|row| row.iter().filter(|e| e.beams.len() > 0).count())
+        .map(|row| row.iter().filter(|e| !e.beams.is_empty()).count())
         .sum();
     res as i64
 }
@@ -1,8 +1,4 @@
-use crate::{common::Point2D, DayTask};
-use std::{
-    collections::{HashMap, HashSet},
-    usize,
-};
+use crate::{common::search::astar, common::Point2D, DayTask, Result};
 
 pub struct Task;
 
@@ -39,38 +35,33 @@ impl State {
     }
 }
 
-struct StateCost {
-    state: State,
-    cost: usize,
-}
-
 impl DayTask<i64> for Task {
     fn day_no(&self) -> u8 {
         17
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part1_test_result(&self) -> i64 {
-        102
+    fn get_part1_test_result(&self) -> Vec<i64> {
+        vec![102]
     }
 
-    fn get_part2_test_result(&self) -> i64 {
-        94
+    fn get_part2_test_result(&self) -> Vec<i64> {
+        vec![94]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
-        run_it(lines, 1, 3)
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        Ok(run_it(lines, 1, 3))
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
-        run_it(lines, 4, 10)
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        Ok(run_it(lines, 4, 10))
     }
 
     fn get_part1_result(&self) -> Option<i64> {
@@ -86,62 +77,24 @@ fn run_it(lines: &Vec<String>, min_steps: u8, max_steps: u8) -> i64 {
     let map = parse_map(lines);
 
     let destination = Point2D::new(map[0].len() as i32 - 1, map.len() as i32 - 1);
-    let mut visited_states = HashSet::new();
-
-    let right_cost = (1..min_steps)
-        .map(|i| map[0][i as usize] as usize)
-        .sum::<usize>();
-    let down_cost = (1..min_steps)
-        .map(|i| map[i as usize][0] as usize)
-        .sum::<usize>();
-    let mut to_visit_states = HashMap::from([
-        (
-            right_cost,
-            vec![State::new(
-                Point2D {
-                    x: min_steps as i32 - 1,
-                    y: 0,
-                },
-                1,
-                0,
-                min_steps,
-            )],
-        ),
-        (
-            down_cost,
-            vec![State::new(
-                Point2D {
-                    x: 0,
-                    y: min_steps as i32 - 1,
-                },
-                0,
-                1,
-                min_steps,
-            )],
-        ),
-    ]);
+    // a full straight run leaves only turning, so the crucible first goes east or south
+    let starts = [
+        State::new(Point2D::new(0, 0), 1, 0, max_steps),
+        State::new(Point2D::new(0, 0), 0, 1, max_steps),
+    ];
+    // every block costs at least 1
+    let heuristic =
+        |state: &State| ((destination.x - state.pos.x) + (destination.y - state.pos.y)) as usize;
 
     assert!(map.len() == map[0].len());
-    while let Some(current_state) = pop_next_to_visit_state(&mut to_visit_states) {
-        // check if we're done
-        if current_state.state.pos == destination {
-            return current_state.cost as i64;
-        }
-
-        if !visited_states.insert(current_state.state.clone()) {
-            continue;
-        }
-
-        // add next states to visit
-        let next_states = get_next_states(&map, &current_state, min_steps, max_steps);
-        for next_state in next_states {
-            if !visited_states.contains(&next_state.state) {
-                add_to_visit_state(&mut to_visit_states, next_state);
-            }
-        }
-    }
-
-    panic!("No solution found")
+    astar(
+        starts,
+        |state| get_next_states(&map, state, min_steps, max_steps),
+        heuristic,
+        |state| state.pos == destination,
+    )
+    .expect("No solution found")
+    .cost as i64
 }
 
 fn parse_map(lines: &Vec<String>) -> Vec<Vec<u8>> {
@@ -156,28 +109,16 @@ fn parse_map(lines: &Vec<String>) -> Vec<Vec<u8>> {
     map
 }
 
-fn add_to_visit_state(to_visit_states: &mut HashMap<usize, Vec<State>>, state: StateCost) {
-    if to_visit_states.contains_key(&state.cost) {
-        to_visit_states
-            .get_mut(&state.cost)
-            .unwrap()
-            .push(state.state);
-    } else {
-        to_visit_states.insert(state.cost, vec![state.state]);
-    }
-}
-
 fn get_next_states(
     map: &Vec<Vec<u8>>,
-    current: &StateCost,
+    current_state: &State,
     min_steps: u8,
     max_steps: u8,
-) -> Vec<StateCost> {
-    if current.state.straight_line_steps < min_steps {
+) -> Vec<(State, usize)> {
+    if current_state.straight_line_steps < min_steps {
         panic!("I should never have so few steps")
     }
-    let current_state = current.state;
-    let mut res = Vec::<StateCost>::new();
+    let mut res = Vec::<(State, usize)>::new();
 
     let mut transformations = vec![
         (rotate(current_state.dx, current_state.dy, true), min_steps),
@@ -193,16 +134,15 @@ fn get_next_states(
             current_state.pos.y + dxy.1 as i32 * steps as i32,
         );
         if new_pos.in_positive_range(map[0].len() as i32, map.len() as i32) {
-            let new_cost = current.cost
-                + (1..=steps)
-                    .map(|i| {
-                        map[(current_state.pos.y + dxy.1 as i32 * i as i32) as usize]
-                            [(current_state.pos.x + dxy.0 as i32 * i as i32) as usize]
-                            as usize
-                    })
-                    .sum::<usize>();
-            res.push(StateCost {
-                state: State::new(
+            let new_cost = (1..=steps)
+                .map(|i| {
+                    map[(current_state.pos.y + dxy.1 as i32 * i as i32) as usize]
+                        [(current_state.pos.x + dxy.0 as i32 * i as i32) as usize]
+                        as usize
+                })
+                .sum::<usize>();
+            res.push((
+                State::new(
                     new_pos,
                     dxy.0,
                     dxy.1,
@@ -212,8 +152,8 @@ fn get_next_states(
                         steps
                     },
                 ),
-                cost: new_cost,
-            })
+                new_cost,
+            ))
         }
     }
 
@@ -226,31 +166,3 @@ fn rotate(x: i8, y: i8, clockwise: bool) -> (i8, i8) {
         false => (y, -x),
     }
 }
-
-fn pop_next_to_visit_state(to_visit_states: &mut HashMap<usize, Vec<State>>) -> Option<StateCost> {
-    if to_visit_states.is_empty() {
-        return None;
-    }
-    let min_key = to_visit_states.keys().min().unwrap().clone();
-    let res = to_visit_states.get_mut(&min_key).unwrap().pop();
-    if to_visit_states.get_mut(&min_key).unwrap().is_empty() {
-        to_visit_states.remove(&min_key);
-    }
-    Some(StateCost {
-        state: res.unwrap(),
-        cost: min_key,
-    })
-}
-
-pub trait StateStore {
-    fn pop_next_to_visit_state(
-        to_visit_states: &mut HashMap<usize, Vec<State>>,
-    ) -> Option<StateCost>;
-    fn get_next_states(map: &Vec<Vec<u8>>, current: &StateCost) -> Vec<StateCost>;
-}
-
-struct AStar {}
-
-impl AStar {
-    fn new() {}
-}
@@ -1,8 +1,6 @@
-use itertools::Itertools;
-
 use crate::{
-    common::{Direction, Point2D},
-    DayTask,
+    common::{geometry::Polygon, Direction, Point2D},
+    DayTask, Result,
 };
 
 pub struct Task;
@@ -27,28 +25,28 @@ impl DayTask<i64> for Task {
         18
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part1_test_result(&self) -> i64 {
-        62
+    fn get_part1_test_result(&self) -> Vec<i64> {
+        vec![62]
     }
 
-    fn get_part2_test_result(&self) -> i64 {
-        952408144115
+    fn get_part2_test_result(&self) -> Vec<i64> {
+        vec![952408144115]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
-        count_inner_new(lines, true)
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        Ok(count_inner_new(lines, true))
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
-        count_inner_new(lines, false)
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        Ok(count_inner_new(lines, false))
     }
 
     fn get_part1_result(&self) -> Option<i64> {
@@ -82,33 +80,15 @@ fn parse_line_part2(line: &str) -> (char, i32) {
     (dir, steps)
 }
 
+// every cube of the trench and of the lagoon it encloses
 fn count_inner_new(lines: &Vec<String>, is_part1: bool) -> i64 {
-    // https://en.wikipedia.org/wiki/Shoelace_formula
-    // https://en.wikipedia.org/wiki/Pick%27s_theorem
-    let points = get_points_in_order(lines, is_part1);
-    let mut total = 0i64;
-    let mut border = 0;
-    for (p1, p2) in points.iter().tuple_windows() {
-        total += p1.x * p2.y - p2.x * p1.y;
-        border += p1.manhattan_distance(p2)
-    }
-    let area = total.abs() as f64 / 2f64;
-    // internal area + border length
-    let res = (area + 0.5 * border as f64 + 1f64) + border as f64;
-    res as i64
-}
-
-fn get_points_in_order(lines: &Vec<String>, use_part1_parser: bool) -> Vec<Point2D<i64>> {
-    let mut current_pos = Point2D::new(0i64, 0);
-    let mut points = vec![Point2D::new(0, 0)];
-    points.extend(lines.iter().map(|line| {
-        let (dir, steps) = if use_part1_parser {
+    let moves = lines.iter().map(|line| {
+        let (dir, steps) = if is_part1 {
             parse_line_part1(line)
         } else {
             parse_line_part2(line)
         };
-        current_pos = current_pos.move_dir(Direction::from_char(dir), steps as i64);
-        current_pos
-    }));
-    points
+        (Direction::from_char(dir), steps as isize)
+    });
+    Polygon::from_moves(Point2D::new(0, 0), moves).lattice_points() as i64
 }
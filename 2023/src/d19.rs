@@ -1,6 +1,6 @@
 use std::{collections::VecDeque, ops::RangeInclusive};
 
-use crate::DayTask;
+use crate::{DayTask, Result};
 use std::collections::HashMap;
 
 pub struct Task;
@@ -73,23 +73,23 @@ impl DayTask<i64> for Task {
         19
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part1_test_result(&self) -> i64 {
-        19114
+    fn get_part1_test_result(&self) -> Vec<i64> {
+        vec![19114]
     }
 
-    fn get_part2_test_result(&self) -> i64 {
-        167409079868000
+    fn get_part2_test_result(&self) -> Vec<i64> {
+        vec![167409079868000]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let mut total = 0;
         let mut rules: HashMap<String, Vec<Rule>> = HashMap::new();
 
@@ -107,10 +107,10 @@ impl DayTask<i64> for Task {
             }
         }
 
-        total
+        Ok(total)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let mut rules: HashMap<String, Vec<Rule>> = HashMap::new();
 
         for line in lines {
@@ -147,7 +147,7 @@ impl DayTask<i64> for Task {
             }
         }
 
-        result
+        Ok(result)
     }
 
     fn get_part1_result(&self) -> Option<i64> {
@@ -210,7 +210,7 @@ fn eval_object(line: &str, rules: &HashMap<String, Vec<Rule>>) -> i64 {
         .split(",")
         .map(|prop| {
             let (name, value) = prop.split_once("=").unwrap();
-            (name.chars().nth(0).unwrap(), value.parse::<i64>().unwrap())
+            (name.chars().next().unwrap(), value.parse::<i64>().unwrap())
         })
         .collect::<HashMap<char, i64>>();
     let mut current_rule = "in";
@@ -261,7 +261,7 @@ fn parse_rule(line: &str) -> (String, Vec<Rule>) {
                     _ => panic!("Invalid condition"),
                 };
                 let value = chars[2..chars.len()]
-                    .into_iter()
+                    .iter()
                     .collect::<String>()
                     .parse::<i64>()
                     .unwrap();
@@ -1,4 +1,4 @@
-use crate::DayTask;
+use crate::{DayTask, Result};
 
 pub struct Task;
 
@@ -9,62 +9,67 @@ Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
 
 impl DayTask<i32> for Task {
-
     fn day_no(&self) -> u8 {
         2
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part1_test_result(&self) -> i32 {
-        8
+    fn get_part1_test_result(&self) -> Vec<i32> {
+        vec![8]
     }
 
-    fn get_part2_test_result(&self) -> i32 {
-        2286
+    fn get_part2_test_result(&self) -> Vec<i32> {
+        vec![2286]
     }
 
-
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i32 {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i32> {
         let games = self.parse(lines);
         let target = Subset {
             green: 13,
             blue: 14,
             red: 12,
         };
-        let result: i32 = games.iter().fold(0, |sum, g|
-            sum + if g.subsets.iter().all(|s| s.smaller_equal(&target)) {g.id as i32} else {0}
-        );
-        result
+        let result: i32 = games.iter().fold(0, |sum, g| {
+            sum + if g.subsets.iter().all(|s| s.smaller_equal(&target)) {
+                g.id as i32
+            } else {
+                0
+            }
+        });
+        Ok(result)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i32 {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i32> {
         let games = self.parse(lines);
-        let powers = games.iter().map(|g| {
-            let mut max_green = 0;
-            let mut max_blue = 0;
-            let mut max_red = 0;
-            for subset in &g.subsets {
-                if subset.green > max_green {
-                    max_green = subset.green;
-                }
-                if subset.blue > max_blue {
-                    max_blue = subset.blue;
-                }
-                if subset.red > max_red {
-                    max_red = subset.red;
+        let powers = games
+            .iter()
+            .map(|g| {
+                let mut max_green = 0;
+                let mut max_blue = 0;
+                let mut max_red = 0;
+                for subset in &g.subsets {
+                    if subset.green > max_green {
+                        max_green = subset.green;
+                    }
+                    if subset.blue > max_blue {
+                        max_blue = subset.blue;
+                    }
+                    if subset.red > max_red {
+                        max_red = subset.red;
+                    }
                 }
-            }
-            let power: i32 = max_blue as i32 * max_green as i32 * max_red as i32;
-            power
-        }).collect::<Vec<i32>>();
-        powers.iter().sum()
+                let power: i32 = max_blue as i32 * max_green as i32 * max_red as i32;
+                power
+            })
+            .collect::<Vec<i32>>();
+        Ok(powers.iter().sum())
     }
 
     fn get_part1_result(&self) -> Option<i32> {
@@ -120,11 +125,7 @@ impl Task {
                     }
                 }
 
-                let subset = Subset {
-                    green,
-                    blue,
-                    red,
-                };
+                let subset = Subset { green, blue, red };
                 game.subsets.push(subset);
             }
             games.push(game);
@@ -1,4 +1,7 @@
-use crate::DayTask;
+use crate::common::cycle::{align, Periodic};
+use crate::common::export::{Drawing, Shape};
+use crate::common::graph::Graph;
+use crate::{AocError, DayTask, Result};
 use std::{
     collections::{HashMap, VecDeque},
     hash::Hash,
@@ -12,14 +15,30 @@ const TI: &str = "broadcaster -> a
 %b -> con
 &con -> output";
 
+// the inverter i1 sends high to f on every 2nd press and i2, behind a 2-bit counter, on
+// every 4th, so rx gets its first low pulse on press 4
+const TI2: &str = "broadcaster -> a, b1
+%a -> i1
+%b1 -> b2
+%b2 -> i2
+&i1 -> f
+&i2 -> f
+&f -> rx";
+
+// the inputs are fed by 12-bit counters, so three of their cycles fit well within this
+const MAX_PRESSES: u64 = 1 << 16;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Pulse {
     Low,
     High,
 }
 trait Module {
-    fn process_pulse(&mut self, input: &str, pulse: Pulse) -> Option<Pulse>;
-    fn init_inputs(&mut self, inputs: &Vec<&str>);
+    fn process_pulse(&mut self, input: usize, pulse: Pulse) -> Option<Pulse>;
+    fn init_inputs(&mut self, inputs: &[usize]);
+    fn is_conjunction(&self) -> bool {
+        false
+    }
 }
 
 struct FlipFlop {
@@ -33,7 +52,7 @@ impl FlipFlop {
 }
 
 impl Module for FlipFlop {
-    fn process_pulse(&mut self, _: &str, pulse: Pulse) -> Option<Pulse> {
+    fn process_pulse(&mut self, _: usize, pulse: Pulse) -> Option<Pulse> {
         match pulse {
             Pulse::Low => {
                 self.state_on = !self.state_on;
@@ -47,16 +66,16 @@ impl Module for FlipFlop {
         }
     }
 
-    fn init_inputs(&mut self, _: &Vec<&str>) {}
+    fn init_inputs(&mut self, _: &[usize]) {}
 }
 
 struct Conjuction {
-    inputs: HashMap<String, Pulse>,
+    inputs: HashMap<usize, Pulse>,
 }
 
 impl Module for Conjuction {
-    fn process_pulse(&mut self, input: &str, pulse: Pulse) -> Option<Pulse> {
-        self.inputs.insert(input.to_string(), pulse);
+    fn process_pulse(&mut self, input: usize, pulse: Pulse) -> Option<Pulse> {
+        self.inputs.insert(input, pulse);
         if self.inputs.values().all(|&p| p == Pulse::High) {
             Some(Pulse::Low)
         } else {
@@ -64,21 +83,25 @@ impl Module for Conjuction {
         }
     }
 
-    fn init_inputs(&mut self, inputs: &Vec<&str>) {
+    fn init_inputs(&mut self, inputs: &[usize]) {
         for input in inputs {
-            self.inputs.insert(input.to_string(), Pulse::Low);
+            self.inputs.insert(*input, Pulse::Low);
         }
     }
+
+    fn is_conjunction(&self) -> bool {
+        true
+    }
 }
 
 struct Broadcaster {}
 
 impl Module for Broadcaster {
-    fn process_pulse(&mut self, _: &str, pulse: Pulse) -> Option<Pulse> {
+    fn process_pulse(&mut self, _: usize, pulse: Pulse) -> Option<Pulse> {
         Some(pulse)
     }
 
-    fn init_inputs(&mut self, _: &Vec<&str>) {}
+    fn init_inputs(&mut self, _: &[usize]) {}
 }
 
 impl DayTask<i64> for Task {
@@ -86,57 +109,105 @@ impl DayTask<i64> for Task {
         20
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI2]
     }
 
-    fn get_part1_test_result(&self) -> i64 {
-        11687500
+    fn get_part1_test_result(&self) -> Vec<i64> {
+        vec![11687500]
     }
 
-    fn get_part2_test_result(&self) -> i64 {
-        1
+    fn get_part2_test_result(&self) -> Vec<i64> {
+        vec![4]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
-        let (links, mut modules) = parse(lines);
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        let (graph, mut modules) = parse(lines);
 
         let mut low_count = 0;
         let mut high_count = 0;
         for _ in 0..1000 {
-            let (l, h, _) = push_the_button(&mut modules, &links, &vec![]);
+            let (l, h, _) = push_the_button(&graph, &mut modules, None);
             low_count += l;
             high_count += h;
         }
 
-        low_count * high_count
+        Ok(low_count * high_count)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, is_test: bool) -> i64 {
-        if is_test {
-            return 1;
-        }
-        // "tx, dd nz ph" need to get low pulse, at the same time
-        // then they all send 'high' to 'ls' which will send 'low' to 'rx'
-        let (links, mut modules) = parse(lines);
-        let to_check = vec!["tx", "dd", "nz", "ph"];
-        let mut found_cycles: HashMap<String, i64> = HashMap::new();
+    // `rx` gets a low pulse once the single conjunction feeding it remembers a high pulse from
+    // each of its inputs, and every input sends one on a cycle of its own
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        let (graph, mut modules) = parse(lines);
+        let rx = graph
+            .get_id("rx")
+            .ok_or_else(|| AocError::Parse("no rx module".to_string()))?;
+        let feeders: Vec<usize> = graph.predecessors(rx).collect();
+        let feeder = match feeders[..] {
+            [feeder] if modules[feeder].as_ref().is_some_and(|m| m.is_conjunction()) => feeder,
+            _ => {
+                return Err(AocError::Parse(
+                    "rx is not fed by a single conjunction".to_string(),
+                ))
+            }
+        };
+        let inputs: Vec<usize> = graph.predecessors(feeder).collect();
+
+        // the presses at which each input sent a high pulse, three show whether they're periodic
+        let mut high_presses: HashMap<usize, Vec<u64>> = HashMap::new();
         let mut count = 0;
-        loop {
-            let (_, _, found) = push_the_button(&mut modules, &links, &to_check);
+        while inputs
+            .iter()
+            .any(|i| high_presses.get(i).map_or(0, |p| p.len()) < 3)
+        {
+            if count == MAX_PRESSES {
+                return Err(AocError::Parse(format!(
+                    "no cycle for every input of {} after {MAX_PRESSES} presses",
+                    graph.name(feeder)
+                )));
+            }
+            let (_, _, found) = push_the_button(&graph, &mut modules, Some(feeder));
             count += 1;
+            // an input sending high more than once in a press still counts that press once
             for module in found {
-                found_cycles.insert(module, count);
+                let presses = high_presses.entry(module).or_default();
+                if presses.last() != Some(&count) {
+                    presses.push(count);
+                }
             }
-            if found_cycles.len() == to_check.len() {
-                break;
+        }
+
+        let mut cycles = vec![];
+        for input in inputs {
+            let times = &high_presses[&input][..3];
+            cycles.push(Periodic::from_times(times).ok_or_else(|| {
+                AocError::Parse(format!(
+                    "{} sends high at presses {times:?}, not on a cycle",
+                    graph.name(input)
+                ))
+            })?);
+        }
+        let presses = align(&cycles).ok_or_else(|| {
+            AocError::Parse("the inputs never send high on the same press".to_string())
+        })?;
+        Ok(presses as i64)
+    }
+
+    // conjunctions are diamonds, everything else stays round
+    fn export_graph(&self, _part: u8, lines: &Vec<String>) -> Result<Drawing> {
+        let (graph, modules) = parse(lines);
+        let mut drawing = Drawing::from_graph(&graph, |_| None);
+        drawing.set_shape("button", Shape::Box);
+        for (id, module) in modules.iter().enumerate() {
+            if module.as_ref().is_some_and(|m| m.is_conjunction()) {
+                drawing.set_shape(graph.name(id), Shape::Diamond);
             }
         }
-        found_cycles.values().product()
+        Ok(drawing)
     }
 
     fn get_part1_result(&self) -> Option<i64> {
@@ -148,32 +219,30 @@ impl DayTask<i64> for Task {
     }
 }
 
+// also returns the modules that sent a high pulse to `watched` during this press
 fn push_the_button(
-    modules: &mut HashMap<String, Box<dyn Module>>,
-    links: &HashMap<String, Vec<&str>>,
-    to_check: &Vec<&str>,
-) -> (i64, i64, Vec<String>) {
+    graph: &Graph,
+    modules: &mut Modules,
+    watched: Option<usize>,
+) -> (i64, i64, Vec<usize>) {
     let mut low_count = 0;
     let mut high_count = 0;
-    let mut signals_found: Vec<String> = Vec::new();
-    let mut pulses_on_inputs = VecDeque::from([("BUTTON", "broadcaster", Pulse::Low)]);
+    let mut signals_found = Vec::new();
+    let button = graph.get_id("button").unwrap();
+    let mut pulses_on_inputs = VecDeque::new();
+    pulses_on_inputs.extend(graph.successors(button).map(|b| (button, b, Pulse::Low)));
     low_count += 1;
-    while !pulses_on_inputs.is_empty() {
-        let (src_module, module_name, pulse) = pulses_on_inputs.pop_front().unwrap();
-        let module = modules.get_mut(module_name);
-        if module.is_none() {
+    while let Some((src_module, module_id, pulse)) = pulses_on_inputs.pop_front() {
+        let Some(module) = modules[module_id].as_mut() else {
             continue;
-        }
-        let module = module.unwrap();
-        let new_pulse = module.process_pulse(&src_module, pulse);
-        if new_pulse == None {
+        };
+        let Some(new_pulse) = module.process_pulse(src_module, pulse) else {
             continue;
-        }
-        let new_pulse = new_pulse.unwrap();
-        for target in links.get(module_name).unwrap() {
-            pulses_on_inputs.push_back((module_name, target, new_pulse));
-            if to_check.contains(target) && new_pulse == Pulse::Low {
-                signals_found.push(target.to_string());
+        };
+        for target in graph.successors(module_id) {
+            pulses_on_inputs.push_back((module_id, target, new_pulse));
+            if Some(target) == watched && new_pulse == Pulse::High {
+                signals_found.push(module_id);
             }
             if new_pulse == Pulse::Low {
                 low_count += 1;
@@ -185,19 +254,21 @@ fn push_the_button(
     (low_count, high_count, signals_found)
 }
 
-fn parse(lines: &Vec<String>) -> (HashMap<String, Vec<&str>>, HashMap<String, Box<dyn Module>>) {
-    let mut links: HashMap<String, Vec<&str>> = HashMap::new();
-    let mut modules: HashMap<String, Box<dyn Module>> = HashMap::new();
+// indexed by the module ids of the graph, None for the button and untyped outputs
+type Modules = Vec<Option<Box<dyn Module>>>;
+
+fn parse(lines: &Vec<String>) -> (Graph, Modules) {
+    let mut graph = Graph::directed();
+    graph.add_edge("button", "broadcaster", ());
+    let mut typed = vec![];
     for line in lines {
         let parts: Vec<&str> = line.split(" -> ").collect();
-        let targets: Vec<&str> = parts[1].split(", ").collect();
         let full_module_name = parts[0];
-        let module_name = if full_module_name == "broadcaster" {
-            parts[0].to_string()
-        } else {
-            parts[0].chars().skip(1).collect::<String>()
-        };
-        links.insert(module_name.clone(), targets);
+        let module_name = full_module_name.trim_start_matches(['%', '&']);
+        let id = graph.id(module_name);
+        for target in parts[1].split(", ") {
+            graph.add_edge(module_name, target, ());
+        }
         let module: Box<dyn Module> = match full_module_name.chars().next().unwrap() {
             '%' => Box::new(FlipFlop::new()),
             '&' => Box::new(Conjuction {
@@ -206,16 +277,12 @@ fn parse(lines: &Vec<String>) -> (HashMap<String, Vec<&str>>, HashMap<String, Bo
             'b' => Box::new(Broadcaster {}),
             _ => panic!("Unknown module type"),
         };
-        modules.insert(module_name, module);
+        typed.push((id, module));
     }
-    for (module_name, _) in links.iter() {
-        let module = modules.get_mut(module_name).unwrap();
-        let sources = links
-            .iter()
-            .filter(|(_, destinations)| destinations.contains(&module_name.as_str()))
-            .map(|(s, _)| s.as_str())
-            .collect();
-        module.init_inputs(&sources);
+    let mut modules: Modules = (0..graph.len()).map(|_| None).collect();
+    for (id, mut module) in typed {
+        module.init_inputs(&graph.predecessors(id).collect::<Vec<_>>());
+        modules[id] = Some(module);
     }
-    (links, modules)
+    (graph, modules)
 }
@@ -1,11 +1,8 @@
 use crate::{
-    common::{MapHashMap, Point2D},
-    DayTask,
-};
-use std::{
-    collections::{HashMap, HashSet, VecDeque},
-    path::Iter,
+    common::{Edges, MapVector, Point2D},
+    AocError, DayTask, Result,
 };
+use std::collections::HashMap;
 
 pub struct Task;
 
@@ -21,80 +18,45 @@ const TI: &str = "...........
 .##..##.##.
 ...........";
 
+// a garden whose distances still change from one ring of tiles to the next this far out is
+// not going to settle
+const MAX_TILE_RADIUS: isize = 16;
+
 impl DayTask<i64> for Task {
     fn day_no(&self) -> u8 {
         21
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec!["6"]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec!["6", "10", "50", "100", "500", "1000", "5000"]
     }
 
-    fn get_part1_test_result(&self) -> i64 {
-        16
+    fn get_part1_test_result(&self) -> Vec<i64> {
+        vec![16]
     }
 
-    fn get_part2_test_result(&self) -> i64 {
-        -1
+    fn get_part2_test_result(&self) -> Vec<i64> {
+        vec![16, 50, 1594, 6536, 167004, 668697, 16733044]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, is_test: bool) -> i64 {
-        let map = MapHashMap::parse_map(lines);
-        let start = map.find('S')[0];
-        let steps = if is_test { 6 } else { 64 };
-        let shortest_paths = bfs_to_all(&map, *start);
-        let gardens = shortest_paths
-            .map
-            .iter()
-            .filter(|(_, &s)| s <= steps && s % 2 == 0)
-            .count();
-        gardens as i64
+    fn run_p1(&self, lines: &Vec<String>, is_test: bool) -> Result<i64> {
+        let (steps, lines) = steps_and_garden(lines, is_test, 64)?;
+        let map = MapVector::new(&lines.to_vec(), |c| c);
+        Ok(count_plots(&map, steps as usize) as i64)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, is_test: bool) -> i64 {
-        if is_test {
-            return -1;
-        }
-        // totally based on https://advent-of-code.xavd.id/writeups/2023/day/21/
-        let map = MapHashMap::parse_map(lines);
-        let start = map.find('S')[0];
-        let shortest_paths = bfs_to_all(&map, *start);
-
-        let grid_size = lines.len();
-        let distance_to_edge = grid_size / 2;
-        assert!(distance_to_edge == 65);
-        let n = (26501365 - distance_to_edge) / grid_size;
-        assert!(n == 202300);
-        let num_odd_tiles = (n + 1) * (n + 1);
-        let num_even_tiles = n * n;
-
-        let odd_corners = shortest_paths
-            .map
-            .iter()
-            .filter(|(_, &s)| s > distance_to_edge as i32 && s % 2 == 1)
-            .count();
-        let even_corners = shortest_paths
-            .map
-            .iter()
-            .filter(|(_, &s)| s > distance_to_edge as i32 && s % 2 == 0)
-            .count();
-        let all_odd = shortest_paths
-            .map
-            .iter()
-            .filter(|(_, &s)| s % 2 == 1)
-            .count();
-        let all_even = shortest_paths
-            .map
-            .iter()
-            .filter(|(_, &s)| s % 2 == 0)
-            .count();
-        let res = num_odd_tiles * all_odd + num_even_tiles * all_even - ((n + 1) * odd_corners)
-            + (n * even_corners);
-        res as i64
+    fn run_p2(&self, lines: &Vec<String>, is_test: bool) -> Result<i64> {
+        let (steps, lines) = steps_and_garden(lines, is_test, 26501365)?;
+        Ok(count_plots_tiled(lines, steps)? as i64)
+    }
+
+    // the examples all walk the same garden and only list their number of steps
+    fn get_test_data(&self, input: &str) -> Vec<String> {
+        input.lines().chain(TI.lines()).map(String::from).collect()
     }
 
     fn get_part1_result(&self) -> Option<i64> {
@@ -106,30 +68,150 @@ impl DayTask<i64> for Task {
     }
 }
 
-fn bfs_to_all(map: &MapHashMap<i32, char>, start: Point2D<i32>) -> MapHashMap<i32, i32> {
-    let mut to_visit = VecDeque::from([(start, 0)]);
-    let mut visited: MapHashMap<i32, i32> = MapHashMap::new();
-    while let Some((pos, steps)) = to_visit.pop_front() {
-        if visited.map.contains_key(&pos) {
+fn steps_and_garden(lines: &[String], is_test: bool, steps: u64) -> Result<(u64, &[String])> {
+    if !is_test {
+        return Ok((steps, lines));
+    }
+    Ok((lines[0].parse()?, &lines[1..]))
+}
+
+// garden plots where the walk can end after exactly `steps` steps: any plot reached in
+// fewer steps of the same parity can be reached again by stepping back and forth
+fn count_plots(map: &MapVector<char>, steps: usize) -> usize {
+    let start = map.find('S')[0];
+    let distances = map.bfs_within([start], |&c| c != '#', steps as u32);
+    distances
+        .values()
+        .filter(|&&d| d as usize % 2 == steps % 2)
+        .count()
+}
+
+// the same on the infinitely tiled garden: the distances are explored ring of tiles after
+// ring of tiles until the tiles on the border of the explored block are just the ones of the
+// ring before, one garden width further away; from there on each border tile stands for all
+// the tiles further out in line with it, and each corner for a whole quadrant
+fn count_plots_tiled(lines: &[String], steps: u64) -> Result<u64> {
+    let size = lines.len();
+    if lines.iter().any(|l| l.len() != size) {
+        return Err(AocError::Parse("the garden is not square".to_string()));
+    }
+    let map = MapVector::new(&lines.to_vec(), |c| c).with_edges(Edges::Tiled);
+    let start = map.find('S')[0];
+    let size = size as isize;
+
+    let mut radius = 1;
+    let mut limit = 4 * size as u32;
+    let (radius, distances) = loop {
+        if radius > MAX_TILE_RADIUS {
+            return Err(AocError::Parse(
+                "the distances in the tiled garden never settle".to_string(),
+            ));
+        }
+        let distances = map.bfs_within([start], |&c| c != '#', limit);
+        let tiles = Tiles {
+            distances: &distances,
+            size,
+        };
+        if !tiles.reached_all(radius + 1) {
+            limit *= 2;
             continue;
         }
-        visited.map.insert(pos, steps);
-        let (y, x) = (pos.y, pos.x);
-        for next in [
-            Point2D::new(x, y - 1),
-            Point2D::new(x, y + 1),
-            Point2D::new(x - 1, y),
-            Point2D::new(x + 1, y),
-        ]
-        .iter()
-        {
-            if map.map.contains_key(&next)
-                && map.map[next] != '#'
-                && !visited.map.contains_key(&next)
-            {
-                to_visit.push_back((*next, steps + 1));
+        if tiles.settled(radius) {
+            break (radius, distances);
+        }
+        radius += 1;
+    };
+
+    let tiles = Tiles {
+        distances: &distances,
+        size,
+    };
+    let mut count = 0;
+    for ty in -radius..=radius {
+        for tx in -radius..=radius {
+            let on_border = [tx, ty].map(|t| t.abs() == radius);
+            for d in tiles.get(tx, ty).into_iter().flatten() {
+                let d = d as u64;
+                count += match on_border {
+                    [false, false] => (d <= steps && d % 2 == steps % 2) as u64,
+                    [true, true] => count_further(d, steps, size as u64, true),
+                    _ => count_further(d, steps, size as u64, false),
+                };
             }
         }
     }
-    visited
+    Ok(count)
+}
+
+// the distances from a BFS on the tiled garden, by tile; the start is in tile 0, 0
+struct Tiles<'a> {
+    distances: &'a HashMap<Point2D<isize>, u32>,
+    size: isize,
+}
+
+impl Tiles<'_> {
+    fn get(&self, tx: isize, ty: isize) -> Vec<Option<u32>> {
+        let (ox, oy) = (tx * self.size, ty * self.size);
+        (0..self.size)
+            .flat_map(|y| (0..self.size).map(move |x| Point2D::new(ox + x, oy + y)))
+            .map(|p| self.distances.get(&p).copied())
+            .collect()
+    }
+
+    // every plot reached in the start's tile was reached in all the tiles up to `radius`
+    // away, so the BFS went far enough
+    fn reached_all(&self, radius: isize) -> bool {
+        let reachable = self
+            .get(0, 0)
+            .iter()
+            .map(Option::is_some)
+            .collect::<Vec<_>>();
+        (-radius..=radius).all(|ty| {
+            (-radius..=radius).all(|tx| {
+                let tile = self.get(tx, ty);
+                reachable.iter().zip(tile).all(|(&r, d)| !r || d.is_some())
+            })
+        })
+    }
+
+    // every tile on the border of the block `radius` tiles around the start is one garden
+    // width closer than the tile beyond it, corners in both directions
+    fn settled(&self, radius: isize) -> bool {
+        [(1, 0), (-1, 0), (0, 1), (0, -1)]
+            .into_iter()
+            .all(|(ux, uy): (isize, isize)| {
+                (-radius..=radius).all(|j| {
+                    let (tx, ty) = (radius * ux + j * uy.abs(), radius * uy + j * ux.abs());
+                    let further = self.get(tx + ux, ty + uy);
+                    self.get(tx, ty)
+                        .into_iter()
+                        .zip(further)
+                        .all(|(d, f)| d.map(|d| d + self.size as u32) == f)
+                })
+            })
+    }
+}
+
+// the plot is at distance d in this tile and d + i * size in the i-th tile beyond it; in a
+// quadrant the i-th diagonal beyond the corner has i + 1 tiles
+fn count_further(d: u64, steps: u64, size: u64, quadrant: bool) -> u64 {
+    if d > steps {
+        return 0;
+    }
+    let last = (steps - d) / size;
+    // the tiles i where d + i * size has the parity of steps
+    let (first, stride) = match (size % 2, (steps - d) % 2) {
+        (0, 0) => (0, 1),
+        (0, _) => return 0,
+        (_, parity) => (parity, 2),
+    };
+    if first > last {
+        return 0;
+    }
+    let n = (last - first) / stride + 1;
+    if quadrant {
+        n * (first + 1) + stride * n * (n - 1) / 2
+    } else {
+        n
+    }
 }
@@ -1,5 +1,5 @@
 use crate::common::{Cube, Point3D};
-use crate::DayTask;
+use crate::{DayTask, Result};
 use crossbeam_channel::unbounded;
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
@@ -19,23 +19,23 @@ impl DayTask<i64> for Task {
         22
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part1_test_result(&self) -> i64 {
-        5
+    fn get_part1_test_result(&self) -> Vec<i64> {
+        vec![5]
     }
 
-    fn get_part2_test_result(&self) -> i64 {
-        7
+    fn get_part2_test_result(&self) -> Vec<i64> {
+        vec![7]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let mut bricks = parse(lines);
         move_down_all(&mut bricks);
 
@@ -48,10 +48,7 @@ impl DayTask<i64> for Task {
                 .enumerate()
                 .fold(HashMap::new(), |mut acc, (id, b)| {
                     for z in b.low_corner.z..=b.high_corner.z {
-                        if acc.get(&(z as u16)).is_none() {
-                            acc.insert(z as u16, Vec::new());
-                        }
-                        acc.get_mut(&(z as u16)).unwrap().push(id as i64);
+                        acc.entry(z as u16).or_default().push(id as i64);
                     }
                     acc
                 });
@@ -64,14 +61,14 @@ impl DayTask<i64> for Task {
                         let mut supporting_bricks =
                             get_bricks_supporting(*on_top_id, &bricks, &bricks_by_z);
                         supporting_bricks.remove(&(bid as i64));
-                        supporting_bricks.len() > 0
+                        !supporting_bricks.is_empty()
                     }) as i64;
         }
 
-        disintegrate_count
+        Ok(disintegrate_count)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let mut bricks = parse(lines);
         move_down_all(&mut bricks);
 
@@ -91,7 +88,7 @@ impl DayTask<i64> for Task {
             });
         }
         for bid in 0..bricks.len() {
-            let new_bricks: Vec<Cube<i64>> = (&bricks)
+            let new_bricks: Vec<Cube<i64>> = bricks
                 .iter()
                 .enumerate()
                 .filter_map(|(id, b)| {
@@ -107,7 +104,7 @@ impl DayTask<i64> for Task {
         for _ in 0..thread_count {
             total_moved += out_rx.recv().unwrap();
         }
-        total_moved
+        Ok(total_moved)
     }
 
     fn get_part1_result(&self) -> Option<i64> {
@@ -136,7 +133,7 @@ fn move_down_all(bricks: &mut Vec<Cube<i64>>) -> Vec<i64> {
             crosses = bricks
                 .iter()
                 .enumerate()
-                .any(|(id, b)| id < brick_id as usize && b.crosses(&moved_down));
+                .any(|(id, b)| id < brick_id && b.crosses(&moved_down));
         }
         if bricks[brick_id] != pre_moved_down {
             moved_bricks_ids.push(brick_id as i64);
@@ -178,7 +175,7 @@ fn get_bricks_on_top(
     bricks: &[Cube<i64>],
     bricks_by_z: &HashMap<u16, Vec<i64>>,
 ) -> HashSet<i64> {
-    get_bricks_in_z_dist(bid as usize, bricks, bricks_by_z, 1)
+    get_bricks_in_z_dist(bid, bricks, bricks_by_z, 1)
 }
 
 fn get_bricks_in_z_dist(
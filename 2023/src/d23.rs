@@ -1,10 +1,12 @@
 use crossbeam_channel::{unbounded, Select};
 use itertools::Itertools;
 
-use crate::{common::Point2D, DayTask};
+use crate::common::export::{point_name, Drawing, Shape};
+use crate::{common::Point2D, DayTask, Result};
 use std::{
     collections::{HashMap, HashSet},
-    isize, thread, time::Duration,
+    thread,
+    time::Duration,
 };
 
 pub struct Task;
@@ -38,34 +40,48 @@ impl DayTask<i64> for Task {
         23
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part1_test_result(&self) -> i64 {
-        94
+    fn get_part1_test_result(&self) -> Vec<i64> {
+        vec![94]
     }
 
-    fn get_part2_test_result(&self) -> i64 {
-        154
+    fn get_part2_test_result(&self) -> Vec<i64> {
+        vec![154]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let (map, start, end) = parse(lines);
         let edges = find_edges(start, map, true);
 
-        find_directed_longest_path(edges, start, end)
+        Ok(find_directed_longest_path(edges, start, end))
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let (map, start, end) = parse(lines);
         let edges = find_edges(start, map, false);
 
-        find_undirected_longest_path(edges, start, end)
+        Ok(find_undirected_longest_path(edges, start, end))
+    }
+
+    // the junctions found by find_edges, one-way when the slopes are respected in part 1
+    fn export_graph(&self, part: u8, lines: &Vec<String>) -> Result<Drawing> {
+        let (map, start, end) = parse(lines);
+        let mut edges: Vec<_> = find_edges(start, map, part == 1).into_iter().collect();
+        edges.sort_by_key(|&((a, b), _)| (a.y, a.x, b.y, b.x));
+        let mut drawing = Drawing::new(part == 1);
+        drawing.set_shape(&point_name(start), Shape::Box);
+        drawing.set_shape(&point_name(end), Shape::Box);
+        for ((a, b), length) in edges {
+            drawing.edge(&point_name(a), &point_name(b), Some(length.to_string()));
+        }
+        Ok(drawing)
     }
 
     fn get_part1_result(&self) -> Option<i64> {
@@ -77,11 +93,15 @@ impl DayTask<i64> for Task {
     }
 }
 
-fn find_edges(start: Point2D<isize>, map: Vec<Vec<char>>, respect_slopes: bool) -> HashMap<(Point2D<isize>, Point2D<isize>), usize> {
+fn find_edges(
+    start: Point2D<isize>,
+    map: Vec<Vec<char>>,
+    respect_slopes: bool,
+) -> HashMap<(Point2D<isize>, Point2D<isize>), usize> {
     let mut visited: HashSet<Point2D<isize>> = HashSet::new();
     let mut to_check = vec![(start, Point2D::new(start.x, 1))];
     let mut edges: HashMap<(Point2D<isize>, Point2D<isize>), usize> = HashMap::new();
-    while to_check.len() > 0 {
+    while !to_check.is_empty() {
         let start_nodes = to_check.remove(0);
         // we need to skip the start node, as otherwise each node will be counted twice
         let mut edge_length = 0;
@@ -113,9 +133,8 @@ fn find_edges(start: Point2D<isize>, map: Vec<Vec<char>>, respect_slopes: bool)
                     'v' => vec![Point2D::new(x, y + 1)],
                     _ => panic!("Invalid char"),
                 }
-            }
-            else {
-                neighbors = vec![(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+            } else {
+                neighbors = [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
                     .iter()
                     .filter(|(nx, ny)| {
                         *ny >= 0
@@ -127,33 +146,35 @@ fn find_edges(start: Point2D<isize>, map: Vec<Vec<char>>, respect_slopes: bool)
                     })
                     .map(|(nx, ny)| Point2D::new(*nx, *ny))
                     .collect();
-                next = neighbors.iter()
+                next = neighbors
+                    .iter()
                     .filter(|p| !visited.contains(p))
-                    .map(|p| *p)
+                    .copied()
                     .collect();
             }
             // if we can choose multiple paths or there's no next (we're at dest)
-            if neighbors.len() >= 3 || next.len() == 0 {
+            if neighbors.len() >= 3 || next.is_empty() {
                 break;
             }
             prev_y = y;
             prev_x = x;
-            y = next[0].y as isize;
-            x = next[0].x as isize;
+            y = next[0].y;
+            x = next[0].x;
         }
         if !correct_path || edge_length <= 1 {
             continue;
         }
         // if !respect_slopes {
-            // visited.insert(Point2D::new(x, y));
+        // visited.insert(Point2D::new(x, y));
         // }
         let end_node = Point2D::new(x, y);
-        if !respect_slopes { 
-            if !edges.contains_key(&(start_nodes.0, end_node)) && !edges.contains_key(&(end_node, start_nodes.0)) {
+        if !respect_slopes {
+            if !edges.contains_key(&(start_nodes.0, end_node))
+                && !edges.contains_key(&(end_node, start_nodes.0))
+            {
                 edges.insert((start_nodes.0, end_node), edge_length);
             }
-        }
-        else {
+        } else {
             edges.insert((start_nodes.0, end_node), edge_length);
         }
         for n in next {
@@ -179,11 +200,14 @@ fn parse(lines: &Vec<String>) -> (Vec<Vec<char>>, Point2D<isize>, Point2D<isize>
     (map, start, end)
 }
 
-fn find_directed_longest_path(edges: HashMap<(Point2D<isize>, Point2D<isize>), usize>, start: Point2D<isize>, end: Point2D<isize>) -> i64 {
+fn find_directed_longest_path(
+    edges: HashMap<(Point2D<isize>, Point2D<isize>), usize>,
+    start: Point2D<isize>,
+    end: Point2D<isize>,
+) -> i64 {
     let nodes = edges
         .keys()
-        .map(|(a, b)| vec![a, b])
-        .flatten()
+        .flat_map(|(a, b)| vec![a, b])
         .collect::<HashSet<_>>();
     let mut costs: HashMap<Point2D<isize>, usize> = HashMap::new();
     costs.insert(start, 0);
@@ -191,13 +215,15 @@ fn find_directed_longest_path(edges: HashMap<(Point2D<isize>, Point2D<isize>), u
         // find nodes with no incoming edges from unknown cost nodes
         let next_nodes: HashSet<&Point2D<isize>> = nodes
             .iter()
-            .filter(|n| 
+            .filter(|n| {
                 edges
                     .keys()
                     .filter(|(a, b)| b == **n && !costs.contains_key(a))
-                    .count() == 0
-                && !costs.contains_key(*n))
-            .map(|n| *n)
+                    .count()
+                    == 0
+                    && !costs.contains_key(*n)
+            })
+            .copied()
             .collect();
         for n in next_nodes {
             let max_path = edges
@@ -213,25 +239,29 @@ fn find_directed_longest_path(edges: HashMap<(Point2D<isize>, Point2D<isize>), u
     costs[&end] as i64
 }
 
-fn find_undirected_longest_path(edges: HashMap<(Point2D<isize>, Point2D<isize>), usize>, start: Point2D<isize>, end: Point2D<isize>) -> i64 {
-    let node_to_edges = edges
-        .keys()
-        .fold(HashMap::new(), |mut acc, (a, b)| {
-            let cost = if edges.keys().contains(&(*a, *b)) {
-                edges[&(*a, *b)]
-            } else {
-                edges[&(*b, *a)]
-            };
-            acc.entry(*a).or_insert(vec![]).push((*b, cost));
-            acc.entry(*b).or_insert(vec![]).push((*a, cost));
-            acc
-        });
+fn find_undirected_longest_path(
+    edges: HashMap<(Point2D<isize>, Point2D<isize>), usize>,
+    start: Point2D<isize>,
+    end: Point2D<isize>,
+) -> i64 {
+    let node_to_edges = edges.keys().fold(HashMap::new(), |mut acc, (a, b)| {
+        let cost = if edges.keys().contains(&(*a, *b)) {
+            edges[&(*a, *b)]
+        } else {
+            edges[&(*b, *a)]
+        };
+        acc.entry(*a).or_insert(vec![]).push((*b, cost));
+        acc.entry(*b).or_insert(vec![]).push((*a, cost));
+        acc
+    });
 
     let (in_tx, in_rx) = unbounded();
     let (out_tx, out_rx) = unbounded();
     let (end_tx, end_rx) = unbounded();
     let thread_count = 20;
-    in_tx.send((start, HashSet::<Point2D<isize>>::new(), 0)).unwrap();
+    in_tx
+        .send((start, HashSet::<Point2D<isize>>::new(), 0))
+        .unwrap();
     let mut longest = 0;
     thread::scope(|s| {
         for _ in 0..thread_count {
@@ -240,17 +270,20 @@ fn find_undirected_longest_path(edges: HashMap<(Point2D<isize>, Point2D<isize>),
                 let my_in_tx = in_tx.clone();
                 let my_out_tx = out_tx.clone();
                 let my_end_tx = end_tx.clone();
-                while let Ok((node, path, length)) = my_in_rx.recv_timeout(Duration::from_millis(1)) {
+                while let Ok((node, path, length)) = my_in_rx.recv_timeout(Duration::from_millis(1))
+                {
                     if node == end {
                         my_out_tx.send(length).unwrap();
                     }
                     for (next_node, cost) in node_to_edges[&node].iter() {
-                        if path.contains(&next_node) {
+                        if path.contains(next_node) {
                             continue;
                         }
                         let mut new_path = path.clone();
                         new_path.insert(node);
-                        my_in_tx.send((next_node.clone(), new_path, length + cost)).unwrap();
+                        my_in_tx
+                            .send((*next_node, new_path, length + cost))
+                            .unwrap();
                     }
                 }
                 drop(my_out_tx);
@@ -258,7 +291,7 @@ fn find_undirected_longest_path(edges: HashMap<(Point2D<isize>, Point2D<isize>),
             });
         }
         let mut finished = 0;
-        let listen_list = vec![&out_rx, &end_rx];
+        let listen_list = [&out_rx, &end_rx];
         let mut sel = Select::new();
         for r in listen_list.iter() {
             sel.recv(r);
@@ -266,7 +299,7 @@ fn find_undirected_longest_path(edges: HashMap<(Point2D<isize>, Point2D<isize>),
         loop {
             let op = sel.select();
             let index = op.index();
-            let msg = op.recv(&listen_list[index]);
+            let msg = op.recv(listen_list[index]);
             if index == 0 {
                 let length = msg.unwrap();
                 if length > longest {
@@ -281,4 +314,4 @@ fn find_undirected_longest_path(edges: HashMap<(Point2D<isize>, Point2D<isize>),
         }
     });
     longest as i64
-}
\ No newline at end of file
+}
@@ -1,5 +1,5 @@
-use crate::DayTask;
-use std::collections::{HashMap, HashSet};
+use crate::common::{export::Drawing, graph::Graph};
+use crate::{AocError, DayTask, Result};
 
 pub struct Task;
 
@@ -22,55 +22,43 @@ impl DayTask<i64> for Task {
         25
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    // day 25 has no second part
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![]
     }
 
-    fn get_part1_test_result(&self) -> i64 {
-        54
+    fn get_part1_test_result(&self) -> Vec<i64> {
+        vec![54]
     }
 
-    fn get_part2_test_result(&self) -> i64 {
-        todo!()
+    fn get_part2_test_result(&self) -> Vec<i64> {
+        vec![]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, is_test: bool) -> i64 {
-        let skip_edges = if is_test {
-            vec![("hfx", "pzl"), ("bvb", "cmg"), ("nvd", "jqt")]
-        } else {
-            // found using a python script to prepare dot input, then dot, inkscape and my own eyes
-            vec![("jzj", "vkb"), ("vrx", "hhx"), ("grh", "nvh")]
-        };
-        let mut next_nodes = HashMap::<String, HashSet<String>>::new();
-        for line in lines {
-            let parts: Vec<&str> = line.split(":").collect();
-            let source = parts[0].to_string();
-            let destinations = parts[1].strip_prefix(" ").unwrap().split(" ").map(|s| s.to_string()).collect::<Vec<String>>();
-            for d in &destinations {
-                next_nodes
-                    .entry(d.to_string())
-                    .or_insert(HashSet::new())
-                    .insert(source.clone());
-                next_nodes
-                    .entry(source.clone())
-                    .or_insert(HashSet::new())
-                    .insert(d.to_string());
-            }
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        let cut = parse(lines)?
+            .min_cut()
+            .ok_or(AocError::Parse("no components".to_string()))?;
+        if cut.edges.len() != 3 {
+            return Err(AocError::Parse(format!(
+                "expected 3 wires to disconnect, the smallest cut has {}",
+                cut.edges.len()
+            )));
         }
-        for se in &skip_edges {
-            next_nodes.get_mut(se.0).unwrap().retain(|n| *n != se.1);
-            next_nodes.get_mut(se.1).unwrap().retain(|n| *n != se.0);
-        }
-        let res = count_nodes(&next_nodes, &skip_edges[0].0);
-        res * count_nodes(&next_nodes, &skip_edges[0].1)
+        let (a, b) = cut.sizes();
+        Ok((a * b) as i64)
+    }
+
+    fn run_p2(&self, _lines: &Vec<String>, _: bool) -> Result<i64> {
+        Err(AocError::NotImplemented)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
-        todo!()
+    fn export_graph(&self, _part: u8, lines: &Vec<String>) -> Result<Drawing> {
+        Ok(Drawing::from_graph(&parse(lines)?, |_| None))
     }
 
     fn get_part1_result(&self) -> Option<i64> {
@@ -82,18 +70,15 @@ impl DayTask<i64> for Task {
     }
 }
 
-fn count_nodes(next_nodes: &HashMap<String, HashSet<String>>, node: &str) -> i64 {
-    let mut visited = HashSet::<String>::new();
-    let mut count = 0;
-    let mut to_visit = vec![node.to_string()];
-    while to_visit.len() > 0 {
-        let current = to_visit.pop().unwrap();
-        if visited.contains(&current) {
-            continue;
+fn parse(lines: &Vec<String>) -> Result<Graph> {
+    let mut graph = Graph::undirected();
+    for line in lines {
+        let (source, destinations) = line
+            .split_once(": ")
+            .ok_or_else(|| AocError::Parse(format!("no connections in {line}")))?;
+        for destination in destinations.split(' ') {
+            graph.add_edge(source, destination, ());
         }
-        visited.insert(current.clone());
-        count += 1;
-        to_visit.extend(next_nodes.get(&current).unwrap().iter().cloned());
     }
-    count
+    Ok(graph)
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::DayTask;
+use crate::{DayTask, Result};
 
 pub struct Task;
 
@@ -20,43 +20,41 @@ impl DayTask<usize> for Task {
         3
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part1_test_result(&self) -> usize {
-        4361
+    fn get_part1_test_result(&self) -> Vec<usize> {
+        vec![4361]
     }
 
-    fn get_part2_test_result(&self) -> usize {
-        467835
+    fn get_part2_test_result(&self) -> Vec<usize> {
+        vec![467835]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> usize {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<usize> {
         // answer: 537732
         let mut result = 0;
-        for numpos in get_numpos(&lines) {
+        for numpos in get_numpos(lines) {
             if numpos.symbol.is_none() {
                 continue;
             }
             result += numpos.num;
         }
-        result
+        Ok(result)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> usize {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<usize> {
         // 84883664
         let mut result = 0;
         let mut gears: HashMap<Position, Vec<SymbolNum>> = HashMap::new();
-        for numpos in get_numpos(&lines) {
+        for numpos in get_numpos(lines) {
             if numpos.symbol.is_some_and(|s| s == '*') {
-                if !gears.contains_key(&numpos.position) {
-                    gears.insert(numpos.position, Vec::new());
-                }
+                gears.entry(numpos.position).or_default();
                 gears.get_mut(&numpos.position).unwrap().push(numpos);
             }
         }
@@ -66,7 +64,7 @@ impl DayTask<usize> for Task {
             }
             result += v[0].num * v[1].num;
         }
-        result
+        Ok(result)
     }
 
     fn get_part1_result(&self) -> Option<usize> {
@@ -97,24 +95,24 @@ fn get_numpos(lines: &Vec<String>) -> Vec<SymbolNum> {
         let mut digit_start_index: Option<usize> = Option::None;
         let mut digit_end_index;
         for (i, c) in line.chars().enumerate() {
-            if c.is_digit(10) && digit_start_index.is_none() {
+            if c.is_ascii_digit() && digit_start_index.is_none() {
                 digit_start_index = Option::Some(i);
                 continue;
             }
-            if c.is_digit(10) && digit_start_index.is_some() {
+            if c.is_ascii_digit() && digit_start_index.is_some() {
                 continue;
             }
-            if !c.is_digit(10) && digit_start_index.is_some() {
+            if !c.is_ascii_digit() && digit_start_index.is_some() {
                 digit_end_index = i - 1;
 
-                let s = get_num(li, &lines, digit_start_index.unwrap(), digit_end_index);
+                let s = get_num(li, lines, digit_start_index.unwrap(), digit_end_index);
                 result.push(s);
                 digit_start_index = Option::None;
             }
         }
-        if digit_start_index.is_some() {
+        if let Some(start_index) = digit_start_index {
             digit_end_index = line.len() - 1;
-            let s = get_num(li, &lines, digit_start_index.unwrap(), digit_end_index);
+            let s = get_num(li, lines, start_index, digit_end_index);
             result.push(s);
         }
     }
@@ -156,7 +154,7 @@ fn get_num(
     'of: for line_index in lines_to_check_indexes {
         for char_index in min_i..=max_i {
             let cur = lines[line_index].chars().nth(char_index).unwrap();
-            if cur == '.' || cur.is_digit(10) {
+            if cur == '.' || cur.is_ascii_digit() {
                 continue;
             }
             has_symbol = true;
@@ -1,4 +1,4 @@
-use crate::DayTask;
+use crate::{DayTask, Result};
 use std::collections::HashSet;
 
 pub struct Task;
@@ -15,35 +15,35 @@ impl DayTask<i32> for Task {
         4
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part1_test_result(&self) -> i32 {
-        13
+    fn get_part1_test_result(&self) -> Vec<i32> {
+        vec![13]
     }
 
-    fn get_part2_test_result(&self) -> i32 {
-        30
+    fn get_part2_test_result(&self) -> Vec<i32> {
+        vec![30]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i32 {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i32> {
         let mut total = 0;
         for line in lines {
-            let match_nums_count = get_winning_numbers(&line);
+            let match_nums_count = get_winning_numbers(line);
             if match_nums_count > 0 {
                 let base: i32 = 2;
                 total += base.pow(match_nums_count - 1);
             }
         }
-        total
+        Ok(total)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i32 {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i32> {
         let mut counts = vec![1; lines.len()];
         for (idx, line) in lines.iter().enumerate() {
             let match_nums_count = get_winning_numbers(line);
@@ -52,7 +52,7 @@ impl DayTask<i32> for Task {
                 counts[i] += card_count;
             }
         }
-        counts.iter().sum()
+        Ok(counts.iter().sum())
     }
 
     fn get_part1_result(&self) -> Option<i32> {
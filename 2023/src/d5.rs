@@ -1,4 +1,4 @@
-use crate::DayTask;
+use crate::{DayTask, Result};
 use crossbeam_channel::unbounded;
 use std::thread;
 use std::{
@@ -47,26 +47,26 @@ impl DayTask<i64> for Task {
         5
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part1_test_result(&self) -> i64 {
-        35
+    fn get_part1_test_result(&self) -> Vec<i64> {
+        vec![35]
     }
 
-    fn get_part2_test_result(&self) -> i64 {
-        46
+    fn get_part2_test_result(&self) -> Vec<i64> {
+        vec![46]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let maps = parse_maps(&lines[1..]);
 
-        lines[0]
+        Ok(lines[0]
             .split(":")
             .last()
             .unwrap()
@@ -74,10 +74,10 @@ impl DayTask<i64> for Task {
             .split(" ")
             .map(|s| find_loc(s.parse::<u64>().unwrap(), &maps))
             .min()
-            .unwrap() as i64
+            .unwrap() as i64)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let maps = parse_maps(&lines[1..]);
         let ranges: Vec<Range> = lines[0]
             .split(":")
@@ -90,7 +90,7 @@ impl DayTask<i64> for Task {
             .chunks(2)
             .map(|c| Range {
                 min: c[0],
-                max: c[0] + c[1],
+                max: c[0] + c[1] - 1,
             })
             .collect();
 
@@ -104,14 +104,9 @@ impl DayTask<i64> for Task {
                 let rx_req = rx_req.clone();
                 let tx_resp = tx_resp.clone();
                 scope.spawn(move || {
-                    loop {
-                        match rx_req.recv() {
-                            Ok(range) => {
-                                let res = find_loc_range(range, maps_ref);
-                                tx_resp.send(res).unwrap();
-                            }
-                            Err(_) => break,
-                        }
+                    while let Ok(range) = rx_req.recv() {
+                        let res = find_loc_range(range, maps_ref);
+                        tx_resp.send(res).unwrap();
                     }
                 });
             }
@@ -129,7 +124,7 @@ impl DayTask<i64> for Task {
                 }
             }
         });
-        min as i64
+        Ok(min as i64)
     }
 
     fn get_part1_result(&self) -> Option<i64> {
@@ -144,7 +139,7 @@ impl DayTask<i64> for Task {
 fn find_loc_range(orig_range: Range, maps: &HashMap<&str, HashMap<Range, i64>>) -> u64 {
     let mut ranges_to_check = HashSet::new();
     ranges_to_check.insert(orig_range);
-    for step in vec![
+    for step in [
         "seed-to-soil",
         "soil-to-fertilizer",
         "fertilizer-to-water",
@@ -155,60 +150,39 @@ fn find_loc_range(orig_range: Range, maps: &HashMap<&str, HashMap<Range, i64>>)
     ] {
         let map = maps.get(step).unwrap();
         let mut new_ranges = HashSet::new();
-        while !ranges_to_check.is_empty() {
-            let range = ranges_to_check.iter().next().cloned().unwrap();
+        while let Some(range) = ranges_to_check.iter().next().cloned() {
             ranges_to_check.remove(&range);
-            for (map_range, offset) in map.iter() {
-                // we have to check 5 cases:
-                // - range entirely within map_range,
-                if range.min >= map_range.min && range.max <= map_range.max {
+            // ranges are inclusive; only the part overlapping a map range is shifted, the rest
+            // is checked again against the other map ranges
+            let overlap = map
+                .iter()
+                .find(|(map_range, _)| map_range.min <= range.max && range.min <= map_range.max);
+            match overlap {
+                Some((map_range, offset)) => {
+                    let min = range.min.max(map_range.min);
+                    let max = range.max.min(map_range.max);
                     new_ranges.insert(Range {
-                        min: (range.min as i64 + offset) as u64,
-                        max: (range.max as i64 + offset) as u64,
-                    });
-                }
-                // - map_range entirely within range,
-                else if range.min < map_range.min && range.max > map_range.max {
-                    ranges_to_check.insert(Range {
-                        min: range.min,
-                        max: map_range.min,
-                    });
-                    new_ranges.insert(Range {
-                        min: (map_range.min as i64 + offset) as u64,
-                        max: (map_range.max as i64 + offset) as u64,
-                    });
-                    ranges_to_check.insert(Range {
-                        min: map_range.max,
-                        max: range.max,
-                    });
-                }
-                // - right side of range overlaps with map_range,
-                else if range.min < map_range.min && range.max > map_range.min {
-                    ranges_to_check.insert(Range {
-                        min: range.min,
-                        max: map_range.min,
-                    });
-                    new_ranges.insert(Range {
-                        min: (map_range.min as i64 + offset) as u64,
-                        max: (range.max as i64 + offset) as u64,
+                        min: (min as i64 + offset) as u64,
+                        max: (max as i64 + offset) as u64,
                     });
+                    if range.min < min {
+                        ranges_to_check.insert(Range {
+                            min: range.min,
+                            max: min - 1,
+                        });
+                    }
+                    if range.max > max {
+                        ranges_to_check.insert(Range {
+                            min: max + 1,
+                            max: range.max,
+                        });
+                    }
                 }
-                // - left side of range overlaps with map_range,
-                else if range.min < map_range.max && range.max > map_range.max {
-                    new_ranges.insert(Range {
-                        min: (range.min as i64 + offset) as u64,
-                        max: (map_range.max as i64 + offset) as u64,
-                    });
-                    ranges_to_check.insert(Range {
-                        min: map_range.max,
-                        max: range.max,
-                    });
+                // range is outside of every map_range
+                None => {
+                    new_ranges.insert(range);
                 }
             }
-            // range is outside of map_range
-            if new_ranges.is_empty() {
-                new_ranges.insert(Range { ..range });
-            }
         }
         ranges_to_check = new_ranges;
     }
@@ -217,7 +191,7 @@ fn find_loc_range(orig_range: Range, maps: &HashMap<&str, HashMap<Range, i64>>)
 
 fn find_loc(seed: u64, maps: &HashMap<&str, HashMap<Range, i64>>) -> u64 {
     let mut current_value = seed;
-    'step: for step in vec![
+    'step: for step in [
         "seed-to-soil",
         "soil-to-fertilizer",
         "fertilizer-to-water",
@@ -1,4 +1,4 @@
-use crate::DayTask;
+use crate::{DayTask, Result};
 
 pub struct Task;
 
@@ -10,23 +10,23 @@ impl DayTask<i32> for Task {
         6
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part1_test_result(&self) -> i32 {
-        288
+    fn get_part1_test_result(&self) -> Vec<i32> {
+        vec![288]
     }
 
-    fn get_part2_test_result(&self) -> i32 {
-        71503
+    fn get_part2_test_result(&self) -> Vec<i32> {
+        vec![71503]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i32 {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i32> {
         let times = lines[0]
             .split(" ")
             .skip(1)
@@ -49,14 +49,26 @@ impl DayTask<i32> for Task {
             margin *= counter;
         }
 
-        margin
+        Ok(margin)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i32 {
-        let time = lines[0].replace(" ", "").split(":").nth(1).unwrap().parse::<i64>().unwrap();
-        let dist = lines[1].replace(" ", "").split(":").nth(1).unwrap().parse::<i64>().unwrap();
-
-        make_race(time, dist)
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i32> {
+        let time = lines[0]
+            .replace(" ", "")
+            .split(":")
+            .nth(1)
+            .unwrap()
+            .parse::<i64>()
+            .unwrap();
+        let dist = lines[1]
+            .replace(" ", "")
+            .split(":")
+            .nth(1)
+            .unwrap()
+            .parse::<i64>()
+            .unwrap();
+
+        Ok(make_race(time, dist))
     }
 
     fn get_part1_result(&self) -> Option<i32> {
@@ -1,4 +1,4 @@
-use crate::DayTask;
+use crate::{DayTask, Result};
 use std::collections::HashMap;
 
 pub struct Task;
@@ -25,28 +25,28 @@ impl DayTask<i32> for Task {
         7
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part1_test_result(&self) -> i32 {
-        6440
+    fn get_part1_test_result(&self) -> Vec<i32> {
+        vec![6440]
     }
 
-    fn get_part2_test_result(&self) -> i32 {
-        5905
+    fn get_part2_test_result(&self) -> Vec<i32> {
+        vec![5905]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i32 {
-        run(lines, false)
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i32> {
+        Ok(run(lines, false))
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i32 {
-        run(lines, true)
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i32> {
+        Ok(run(lines, true))
     }
 
     fn get_part1_result(&self) -> Option<i32> {
@@ -114,7 +114,7 @@ fn get_cards(hand: &str) -> HashMap<char, u32> {
 
 fn get_hand_type_with_jokers(cards: &mut HashMap<char, u32>) -> HandType {
     if !cards.keys().any(|c| *c == 'J') {
-        return get_hand_type(cards)
+        return get_hand_type(cards);
     }
 
     let jokers_count = cards.remove(&'J').unwrap();
@@ -1,6 +1,7 @@
-use crate::DayTask;
-use std::collections::HashMap;
-use num::integer::lcm;
+use crate::common::cycle::{align, find_cycle, Cycle, Periodic};
+use crate::common::graph::Graph;
+use crate::{AocError, DayTask, Result};
+use itertools::Itertools;
 
 pub struct Task;
 
@@ -21,38 +22,35 @@ const TI2: &str = "LR
 22Z = (22B, 22B)
 XXX = (XXX, XXX)";
 
-struct ZInfo {
-    first_z_index: usize,
-    cycle_length: usize,
-}
-
 impl DayTask<usize> for Task {
     fn day_no(&self) -> u8 {
         8
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI2
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI2]
     }
 
-    fn get_part1_test_result(&self) -> usize {
-        6
+    fn get_part1_test_result(&self) -> Vec<usize> {
+        vec![6]
     }
 
-    fn get_part2_test_result(&self) -> usize {
-        6
+    fn get_part2_test_result(&self) -> Vec<usize> {
+        vec![6]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> usize {
-        do_steps(lines, |e| e == "AAA", |e| e == "ZZZ")
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<usize> {
+        Ok(do_steps(lines, |e| e == "AAA", |e| e == "ZZZ"))
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> usize {
-        do_cycles(lines)
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<usize> {
+        do_cycles(lines).ok_or_else(|| {
+            AocError::Parse("the ghosts are never all on a Z node together".to_string())
+        })
     }
 
     fn get_part1_result(&self) -> Option<usize> {
@@ -64,54 +62,52 @@ impl DayTask<usize> for Task {
     }
 }
 
-fn do_cycles(lines: &Vec<String>) -> usize {
-    let (choices, map) = parse(lines);
-    let start_elements = map
-        .keys()
-        .filter(|e| e.ends_with("A"))
-        .map(|e| *e)
-        .collect::<Vec<&str>>();
-
-    let mut cycle_info: HashMap<&str, ZInfo> = HashMap::new();
-    for el in &start_elements {
-        let mut choice_index = 0;
-        let mut first_z = None;
-        let mut current = *el;
-        let mut counter = 0;
-        let mut z_info = ZInfo {
-            first_z_index: 0,
-            cycle_length: 0,
-        };
-        loop {
-            let choice = choices[choice_index];
-            current = if choice == 'L' {
-                map[current].0
-            } else {
-                map[current].1
-            };
-            counter += 1;
-            if current.ends_with("Z") {
-                if first_z.is_none() {
-                    first_z = Some(current.to_string());
-                    z_info.first_z_index = counter;
-                } else {
-                    z_info.cycle_length = counter - z_info.first_z_index;
-                    cycle_info.insert(el, z_info);
-                    if current != first_z.unwrap() {
-                        panic!("Found 2nd z, but it's not the same as the first one");
-                    }
-                    break;
-                }
-            }
-            choice_index = (choice_index + 1) % choices.len();
-        }
+// every ghost ends up looping over the same (node, instruction) states, and is on a Z node at
+// fixed steps of its loop; the Z nodes don't need to come exactly one loop after the start
+fn do_cycles(lines: &Vec<String>) -> Option<usize> {
+    let (choices, graph) = parse(lines);
+    let is_end = names_matching(&graph, |e| e.ends_with('Z'));
+    let walks: Vec<(Cycle, Vec<(usize, usize)>)> = (0..graph.len())
+        .filter(|&id| graph.name(id).ends_with('A'))
+        .map(|start| {
+            find_cycle((start, 0), |&(node, index)| {
+                (
+                    graph.edges(node)[choices[index]].0,
+                    (index + 1) % choices.len(),
+                )
+            })
+        })
+        .collect();
+    let all_on_end = |step| {
+        walks
+            .iter()
+            .all(|(cycle, states)| is_end[states[cycle.index_of(step)].0])
+    };
+
+    // until every ghost is in its loop, the steps are just checked one by one
+    let prefix = walks.iter().map(|(cycle, _)| cycle.start).max()?;
+    if let Some(step) = (1..prefix).find(|&step| all_on_end(step)) {
+        return Some(step);
     }
-
-    let cycle_lengths = cycle_info
-        .values()
-        .map(|z| z.cycle_length)
-        .collect::<Vec<usize>>();
-    cycle_lengths.iter().fold(1, |acc, &x| lcm(acc, x))
+    let z_events: Vec<Vec<Periodic>> = walks
+        .iter()
+        .map(|(cycle, states)| {
+            (cycle.start..cycle.start + cycle.period)
+                .filter(|&i| is_end[states[i].0])
+                .map(|i| Periodic {
+                    first: i as u64,
+                    period: cycle.period as u64,
+                })
+                .collect()
+        })
+        .collect();
+    z_events
+        .iter()
+        .map(|events| events.iter().copied())
+        .multi_cartesian_product()
+        .filter_map(|events| align(&events))
+        .min()
+        .map(|step| step as usize)
 }
 
 fn do_steps(
@@ -119,28 +115,20 @@ fn do_steps(
     starter_predicate: fn(&str) -> bool,
     stop_condition: fn(&str) -> bool,
 ) -> usize {
-    let (choices, map) = parse(lines);
+    let (choices, graph) = parse(lines);
+    let is_end = names_matching(&graph, stop_condition);
     let mut index = 0;
     let mut counter = 0;
-    let mut current_elements = map
-        .keys()
-        .filter(|e| starter_predicate(e))
-        .map(|e| *e)
-        .collect::<Vec<&str>>();
+    let mut current_elements = (0..graph.len())
+        .filter(|&id| starter_predicate(graph.name(id)))
+        .collect::<Vec<usize>>();
     loop {
-        let mut new_elements = Vec::new();
         let choice = choices[index];
-        for current in &current_elements {
-            let next = if choice == 'L' {
-                map[*current].0
-            } else {
-                map[*current].1
-            };
-            new_elements.push(next);
+        for current in current_elements.iter_mut() {
+            *current = graph.edges(*current)[choice].0;
         }
         counter += 1;
-        current_elements = new_elements;
-        if current_elements.iter().all(|e| stop_condition(e)) {
+        if current_elements.iter().all(|&e| is_end[e]) {
             break;
         }
         index = (index + 1) % choices.len();
@@ -149,18 +137,28 @@ fn do_steps(
     counter
 }
 
-fn parse(lines: &Vec<String>) -> (Vec<char>, HashMap<&str, (&str, &str)>) {
-    let choices = lines[0].chars().collect::<Vec<char>>();
-    let map = lines[2..]
-        .iter()
-        .map(|l| {
-            let parts = l.split(" = ").collect::<Vec<&str>>();
-            let lr = parts[1]
-                .trim_matches(&['(', ')'])
-                .split(", ")
-                .collect::<Vec<&str>>();
-            (parts[0], (lr[0], lr[1]))
-        })
-        .collect::<HashMap<&str, (&str, &str)>>();
-    (choices, map)
+// the names are only looked at once, the walks compare node ids
+fn names_matching(graph: &Graph, predicate: impl Fn(&str) -> bool) -> Vec<bool> {
+    (0..graph.len())
+        .map(|id| predicate(graph.name(id)))
+        .collect()
+}
+
+// every node has its left edge first and its right edge second, so a choice is the edge index
+fn parse(lines: &Vec<String>) -> (Vec<usize>, Graph) {
+    let choices = lines[0]
+        .chars()
+        .map(|c| if c == 'L' { 0 } else { 1 })
+        .collect::<Vec<usize>>();
+    let mut graph = Graph::directed();
+    for l in &lines[2..] {
+        let parts = l.split(" = ").collect::<Vec<&str>>();
+        let lr = parts[1]
+            .trim_matches(['(', ')'])
+            .split(", ")
+            .collect::<Vec<&str>>();
+        graph.add_edge(parts[0], lr[0], ());
+        graph.add_edge(parts[0], lr[1], ());
+    }
+    (choices, graph)
 }
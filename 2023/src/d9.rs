@@ -1,4 +1,4 @@
-use crate::DayTask;
+use crate::{DayTask, Result};
 use std::vec;
 
 pub struct Task;
@@ -8,33 +8,32 @@ const TI: &str = "0 3 6 9 12 15
 10 13 16 21 30 45";
 
 impl DayTask<i32> for Task {
-
     fn day_no(&self) -> u8 {
         9
     }
 
-    fn get_part1_test_input(&self) -> &'static str {
-        TI
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part2_test_input(&self) -> &'static str {
-        TI
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
     }
 
-    fn get_part1_test_result(&self) -> i32 {
-        114
+    fn get_part1_test_result(&self) -> Vec<i32> {
+        vec![114]
     }
 
-    fn get_part2_test_result(&self) -> i32 {
-        2
+    fn get_part2_test_result(&self) -> Vec<i32> {
+        vec![2]
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i32 {
-        lines.iter().map(|l| do_one_line(l)).sum()
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i32> {
+        Ok(lines.iter().map(|l| do_one_line(l)).sum())
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i32 {
-        lines.iter().map(|l| do_one_line_part2(l)).sum()
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i32> {
+        Ok(lines.iter().map(|l| do_one_line_part2(l)).sum())
     }
 
     fn get_part1_result(&self) -> Option<i32> {
@@ -57,7 +56,7 @@ fn do_one_line_part2(l: &str) -> i32 {
         extras.push(next_num);
     }
 
-    extras.last().unwrap().clone()
+    *extras.last().unwrap()
 }
 
 fn do_one_line(l: &str) -> i32 {
@@ -77,7 +76,10 @@ fn do_one_line(l: &str) -> i32 {
 }
 
 fn get_series(l: &str) -> Vec<Vec<i32>> {
-    let nums: Vec<i32> = l.split_whitespace().map(|s| s.parse::<i32>().unwrap()).collect();
+    let nums: Vec<i32> = l
+        .split_whitespace()
+        .map(|s| s.parse::<i32>().unwrap())
+        .collect();
     let mut prev = nums.clone();
     let mut next: Vec<i32>;
     let mut series = vec![nums];
@@ -1,4 +1,4 @@
-use crate::DayTask;
+use crate::{DayTask, Result};
 use std::collections::HashMap;
 
 pub struct Task;
@@ -39,7 +39,7 @@ impl DayTask<i64> for Task {
         Some(18997088)
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let (mut l1, mut l2) = get_lists(lines);
         l1.sort();
         l2.sort();
@@ -48,20 +48,17 @@ impl DayTask<i64> for Task {
             .zip(l2.iter())
             .map(|(n1, n2)| (n1 - n2).abs())
             .sum();
-        res
+        Ok(res)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let (l1, l2) = get_lists(lines);
         let l2_freq = l2.iter().fold(HashMap::new(), |mut acc, &n| {
             *acc.entry(n).or_insert(0) += 1;
             acc
         });
-        let sum = l1
-            .iter()
-            .map(|n| l2_freq.get(n).or(Some(&0)).unwrap() * n)
-            .sum();
-        sum
+        let sum = l1.iter().map(|n| l2_freq.get(n).unwrap_or(&0) * n).sum();
+        Ok(sum)
     }
 }
 
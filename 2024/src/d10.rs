@@ -1,8 +1,8 @@
 use crate::{
-    common::{MapVector, Point2D, Path},
-    DayTask,
+    common::{MapVector, Point2D},
+    DayTask, Result,
 };
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashSet, VecDeque};
 
 pub struct Task;
 
@@ -44,20 +44,22 @@ impl DayTask<i64> for Task {
         Some(1651)
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
-        run(lines, true)
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        Ok(run(lines, true))
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
-        run(lines, false)
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        Ok(run(lines, false))
     }
-
 }
 
 fn run(lines: &Vec<String>, unique: bool) -> i64 {
     let map: MapVector<i8> = MapVector::new(lines, |c| (c.to_digit(10).unwrap()) as i8);
     let starts = map.find(0);
-    starts.iter().map(|start| get_quality(&map, start, unique)).sum()
+    starts
+        .iter()
+        .map(|start| get_quality(&map, start, unique))
+        .sum()
 }
 
 fn get_quality(map: &MapVector<i8>, start: &Point2D<isize>, unique: bool) -> i64 {
@@ -74,9 +76,9 @@ fn get_quality(map: &MapVector<i8>, start: &Point2D<isize>, unique: bool) -> i64
         queue.extend(neighbors);
     }
     if unique {
-    let mut unique_res: HashSet<Point2D<isize>> = HashSet::new();
-    unique_res.extend(res.iter().collect::<HashSet<&Point2D<isize>>>());
-    unique_res.len() as i64
+        let mut unique_res: HashSet<Point2D<isize>> = HashSet::new();
+        unique_res.extend(res.iter().collect::<HashSet<&Point2D<isize>>>());
+        unique_res.len() as i64
     } else {
         res.len() as i64
     }
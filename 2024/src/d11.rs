@@ -1,5 +1,4 @@
-use crate::DayTask;
-use std::collections::{HashMap, VecDeque};
+use crate::{DayTask, Result};
 
 pub struct Task;
 
@@ -34,15 +33,15 @@ impl DayTask<i64> for Task {
         Some(218817038947400)
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
-        run(lines, 25)
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        Ok(run(lines, 25))
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _is_test: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _is_test: bool) -> Result<i64> {
         if _is_test {
-            return -1;
+            return Ok(-1);
         }
-        run(lines, 75)
+        Ok(run(lines, 75))
     }
 }
 
@@ -65,7 +64,7 @@ fn count_stones(n: i64, iter: i8) -> i64 {
     }
 
     let stone_str = n.to_string();
-    if stone_str.len() % 2 == 0 {
+    if stone_str.len().is_multiple_of(2) {
         let high = stone_str[0..stone_str.len() / 2].parse::<i64>().unwrap();
         let low = stone_str[stone_str.len() / 2..].parse::<i64>().unwrap();
         return count_stones(high, iter - 1) + count_stones(low, iter - 1);
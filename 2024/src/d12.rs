@@ -1,8 +1,6 @@
-use itertools::Iterate;
-
 use crate::{
     common::{MapVector, Point2D},
-    DayTask,
+    DayTask, Result,
 };
 use std::{collections::HashSet, vec};
 
@@ -52,7 +50,6 @@ AABAA";
 struct Region {
     area: usize,
     perimeter: usize,
-    coords: HashSet<Point2D<isize>>,
 }
 
 impl DayTask<i64> for Task {
@@ -84,45 +81,37 @@ impl DayTask<i64> for Task {
         None
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
-        run(lines, true)
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        Ok(run(lines, true))
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
-        run(lines, false)
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        Ok(run(lines, false))
     }
 }
 
 fn run(lines: &Vec<String>, perimeter_mode: bool) -> i64 {
     let map = MapVector::new(lines, |c| c);
-    let map_size = map.get_size();
-    let mut visited: HashSet<Point2D<isize>> = HashSet::new();
-    let mut regions = vec![];
-    for y in 0..map_size.y as isize {
-        for x in 0..map_size.x as isize {
-            let pos = Point2D::new(x, y);
-            if !visited.contains(&pos) {
-                let region = find_region(&map, &pos, perimeter_mode);
-                visited.extend(region.coords.iter().copied());
-                regions.push(region);
-            }
+    let (labels, count) = map.components(|a, b| a == b);
+    let mut regions_coords = vec![HashSet::new(); count];
+    for (y, row) in labels.map.iter().enumerate() {
+        for (x, &label) in row.iter().enumerate() {
+            regions_coords[label].insert(Point2D::new(x as isize, y as isize));
         }
     }
+    let regions: Vec<Region> = regions_coords
+        .into_iter()
+        .map(|coords| find_region(&map, coords, perimeter_mode))
+        .collect();
     regions.iter().map(|r| (r.area * r.perimeter) as i64).sum()
 }
 
-fn find_region(map: &MapVector<char>, pos: &Point2D<isize>, perimeter_mode: bool) -> Region {
-    let mut visited_local: HashSet<Point2D<isize>> = HashSet::new();
-    let mut to_visit = vec![*pos];
-    let terrain = map[*pos];
-    while let Some(current) = to_visit.pop() {
-        visited_local.insert(current);
-        for neighbor_pos in map.get_neighbors_pos(&current, |&np| map[np] == terrain) {
-            if !visited_local.contains(&neighbor_pos) {
-                to_visit.push(neighbor_pos);
-            }
-        }
-    }
+fn find_region(
+    map: &MapVector<char>,
+    visited_local: HashSet<Point2D<isize>>,
+    perimeter_mode: bool,
+) -> Region {
+    let terrain = map[*visited_local.iter().next().unwrap()];
     let perimeter = if perimeter_mode {
         find_perimeter(map, &visited_local, terrain)
     } else {
@@ -130,8 +119,7 @@ fn find_region(map: &MapVector<char>, pos: &Point2D<isize>, perimeter_mode: bool
     };
     Region {
         area: visited_local.len(),
-        perimeter: perimeter,
-        coords: visited_local,
+        perimeter,
     }
 }
 
@@ -251,7 +239,7 @@ fn filter_peri(
     peri.iter()
         .filter(|p| {
             let neighbors_pos = map.get_neighbors_pos(p, |&np| map[np] == terrain);
-            if neigh_count_expr(neighbors_pos.len() as u8) == false {
+            if !neigh_count_expr(neighbors_pos.len() as u8) {
                 return false;
             }
             filter(p, &neighbors_pos)
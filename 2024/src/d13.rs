@@ -1,4 +1,9 @@
-use crate::{common::Point2D, DayTask};
+use num::{BigInt, Signed, ToPrimitive};
+
+use crate::{
+    common::{linalg, Point2D},
+    DayTask, Result,
+};
 
 pub struct Task;
 
@@ -53,42 +58,34 @@ impl DayTask<i64> for Task {
         Some(77204516023437)
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let machines = parse_machines(lines);
-        machines.iter().map(|m| solve_one(&m, false)).sum()
+        Ok(machines.iter().map(|m| solve_one(m, false)).sum())
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _is_test: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _is_test: bool) -> Result<i64> {
         let machines = parse_machines(lines);
-        machines.iter().map(|m| solve_one(&m, true)).sum()
+        Ok(machines.iter().map(|m| solve_one(m, true)).sum())
     }
 }
 
-fn solve_one(m: &&Machine, add_factor: bool) -> i64 {
+// a * A + b * B = prize, with whole non-negative presses; buttons pointing the same way
+// don't give a unique number of presses, and never happen in the inputs
+fn solve_one(m: &Machine, add_factor: bool) -> i64 {
     let fact: isize = 10000000000000;
-    let px = if add_factor {
-        m.prize.x + fact
-    } else {
-        m.prize.x
-    };
-    let py = if add_factor {
-        m.prize.y + fact
-    } else {
-        m.prize.y
-    };
-
-    let b_presses = (px as f64 - py as f64 * m.button_a.x as f64 / m.button_a.y as f64)
-        / (m.button_b.x as f64 - m.button_b.y as f64 * m.button_a.x as f64 / m.button_a.y as f64);
-    let a_presses = (py as f64 - b_presses * m.button_b.y as f64) / m.button_a.y as f64;
-    let a = a_presses.round() as isize;
-    let b = b_presses.round() as isize;
-    let cost =
-        if px == a * m.button_a.x + b * m.button_b.x && py == a * m.button_a.y + b * m.button_b.y {
-            (a as i64) * 3 + (b as i64)
-        } else {
-            0
-        };
-    cost
+    let offset = if add_factor { fact } else { 0 };
+    let matrix = vec![
+        vec![m.button_a.x, m.button_b.x],
+        vec![m.button_a.y, m.button_b.y],
+    ];
+    let prize = [m.prize.x + offset, m.prize.y + offset];
+    match linalg::solve(&matrix, &prize).integers().as_deref() {
+        Some([a, b]) if !a.is_negative() && !b.is_negative() => {
+            let tokens: BigInt = a * 3 + b;
+            tokens.to_i64().unwrap()
+        }
+        _ => 0,
+    }
 }
 
 fn parse_machines(lines: &[String]) -> Vec<Machine> {
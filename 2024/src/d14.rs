@@ -1,4 +1,7 @@
-use crate::{common::Point2D, DayTask};
+use crate::{
+    common::{Edges, MapVector, Point2D},
+    DayTask, Result,
+};
 
 pub struct Task;
 
@@ -44,112 +47,83 @@ impl DayTask<i64> for Task {
         Some(7861)
     }
 
-    fn run_p1(&self, lines: &Vec<String>, is_test: bool) -> i64 {
-        let mut bots = parse_bots(lines);
-        if is_test {
-            get_safety_factor(&mut bots, 11, 7, 100)
+    fn run_p1(&self, lines: &Vec<String>, is_test: bool) -> Result<i64> {
+        let bots = parse_bots(lines);
+        Ok(if is_test {
+            get_safety_factor(&bots, 11, 7, 100)
         } else {
-            get_safety_factor(&mut bots, 101, 103, 100)
-        }
+            get_safety_factor(&bots, 101, 103, 100)
+        })
     }
 
-    fn run_p2(&self, lines: &Vec<String>, is_test: bool) -> i64 {
-        let mut bots = parse_bots(lines);
-        if is_test {
+    fn run_p2(&self, lines: &Vec<String>, is_test: bool) -> Result<i64> {
+        let bots = parse_bots(lines);
+        Ok(if is_test {
             1
         } else {
-            find_christmas_tree(&mut bots, 101, 103)
-        }
+            find_christmas_tree(&bots, 101, 103)
+        })
     }
 }
 
-fn get_safety_factor(bots: &mut [Bot], width: i64, height: i64, iterations: i32) -> i64 {
-    let mut ul_count = 0;
-    let mut ur_count = 0;
-    let mut ll_count = 0;
-    let mut lr_count = 0;
+// number of bots on every tile after some seconds, the floor wraps around at its edges
+fn move_bots(bots: &[Bot], width: usize, height: usize, seconds: isize) -> MapVector<i64> {
+    let mut floor = MapVector::empty(Point2D::new(width, height), 0).with_edges(Edges::Wrapping);
+    for bot in bots {
+        floor[bot
+            .position
+            .move_dxy(bot.velocity.x * seconds, bot.velocity.y * seconds)] += 1;
+    }
+    floor
+}
 
+fn get_safety_factor(bots: &[Bot], width: usize, height: usize, seconds: isize) -> i64 {
+    let floor = move_bots(bots, width, height, seconds);
     let mid_x = width / 2;
     let mid_y = height / 2;
 
-    for bot in bots {
-        let mut new_x = bot.position.x;
-        let mut new_y = bot.position.y;
-        for _ in 0..iterations {
-            new_x = (new_x + bot.velocity.x) % width;
-            if new_x < 0 {
-                new_x += width;
+    // upper left, upper right, lower left, lower right; bots in the middle don't count
+    let mut quadrants = [0; 4];
+    for (y, row) in floor.map.iter().enumerate() {
+        for (x, &count) in row.iter().enumerate() {
+            if x != mid_x && y != mid_y {
+                quadrants[(x > mid_x) as usize + 2 * (y > mid_y) as usize] += count;
             }
-            new_y = (new_y + bot.velocity.y) % height;
-            if new_y < 0 {
-                new_y += height;
-            }
-        }
-        bot.position.x = new_x;
-        bot.position.y = new_y;
-        if bot.position.x < mid_x && bot.position.y < mid_y {
-            ul_count += 1;
-        } else if bot.position.x > mid_x && bot.position.y < mid_y {
-            ur_count += 1;
-        } else if bot.position.x < mid_x && bot.position.y > mid_y {
-            ll_count += 1;
-        } else if bot.position.x > mid_x && bot.position.y > mid_y {
-            lr_count += 1;
         }
     }
-    ul_count * ur_count * ll_count * lr_count
+    quadrants.iter().product()
 }
 
-fn find_christmas_tree(bots: &mut [Bot], width: i64, height: i64) -> i64 {
-    let mut iters = 0;
+fn find_christmas_tree(bots: &[Bot], width: usize, height: usize) -> i64 {
+    let mut seconds = 1;
     loop {
-        for bot in &mut *bots {
-            let mut new_x = bot.position.x;
-            let mut new_y = bot.position.y;
-            new_x = (new_x + bot.velocity.x) % width;
-            if new_x < 0 {
-                new_x += width;
-            }
-            new_y = (new_y + bot.velocity.y) % height;
-            if new_y < 0 {
-                new_y += height;
-            }
-            bot.position.x = new_x;
-            bot.position.y = new_y;
+        let floor = move_bots(bots, width, height, seconds);
+        // the tree has a frame, so look for a long line of bots
+        if floor
+            .map
+            .iter()
+            .any(|row| row.windows(20).any(|w| w.iter().all(|&count| count > 0)))
+        {
+            print_tree(&floor);
+            return seconds as i64;
         }
-
-        for y in 0..height {
-            let mut row = ".".repeat(width as usize);
-            for bot in bots.iter() {
-                if bot.position.y == y {
-                    row.replace_range(bot.position.x as usize..bot.position.x as usize + 1, "#");
-                }
-            }
-            if row.contains("####################") {
-                print_tree(bots, width, height);
-                return iters + 1;
-            }
-        }
-
-        iters += 1;
+        seconds += 1;
     }
 }
 
-fn print_tree(bots: &[Bot], width: i64, height: i64) {
-    for y in 0..height {
-        let mut row = ".".repeat(width as usize);
-        for bot in bots.iter() {
-            if bot.position.y == y {
-                row.replace_range(bot.position.x as usize..bot.position.x as usize + 1, "#");
-            }
-        }
+fn print_tree(floor: &MapVector<i64>) {
+    for row in &floor.map {
+        let row: String = row
+            .iter()
+            .map(|&count| if count > 0 { '#' } else { '.' })
+            .collect();
         println!("{}", row);
     }
 }
 
 struct Bot {
-    position: Point2D<i64>,
-    velocity: Point2D<i64>,
+    position: Point2D<isize>,
+    velocity: Point2D<isize>,
 }
 
 fn parse_bots(lines: &[String]) -> Vec<Bot> {
@@ -160,13 +134,13 @@ fn parse_bots(lines: &[String]) -> Vec<Bot> {
             let pos = parts[0]
                 .trim_start_matches("p=")
                 .split(',')
-                .map(|n| n.parse::<i64>().unwrap())
-                .collect::<Vec<i64>>();
+                .map(|n| n.parse::<isize>().unwrap())
+                .collect::<Vec<isize>>();
             let vel = parts[1]
                 .trim_start_matches("v=")
                 .split(',')
-                .map(|n| n.parse::<i64>().unwrap())
-                .collect::<Vec<i64>>();
+                .map(|n| n.parse::<isize>().unwrap())
+                .collect::<Vec<isize>>();
 
             Bot {
                 position: Point2D::new(pos[0], pos[1]),
@@ -1,6 +1,6 @@
 use crate::{
     common::{Direction, MapVector, Point2D},
-    DayTask,
+    DayTask, Result,
 };
 
 pub struct Task;
@@ -77,16 +77,16 @@ impl DayTask<i64> for Task {
         Some(1337648)
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let (start_pos, mut map, moves) = parse(lines, false);
         do_moves(&mut map, &start_pos, &moves, false);
-        calc_res(&map, 'O')
+        Ok(calc_res(&map, 'O'))
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let (start_pos, mut map, moves) = parse(lines, true);
         do_moves(&mut map, &start_pos, &moves, true);
-        calc_res(&map, '[')
+        Ok(calc_res(&map, '['))
     }
 }
 
@@ -103,7 +103,7 @@ fn do_moves(
     moves: &[char],
     big_boxes: bool,
 ) {
-    let mut robot_pos = start_pos.clone();
+    let mut robot_pos = *start_pos;
     for &m in moves {
         let dir = match m {
             '^' => Direction::North,
@@ -121,7 +121,7 @@ fn do_moves(
             continue;
         }
         // else, there is a box
-        if big_boxes == false && try_move_box(map, next_pos, dir) {
+        if !big_boxes && try_move_box(map, next_pos, dir) {
             robot_pos = next_pos;
         }
         if big_boxes {
@@ -318,7 +318,7 @@ fn parse(lines: &Vec<String>, expand: bool) -> (Point2D<isize>, MapVector<char>,
     }
     let mut map = MapVector::new(&map_lines, |c| c);
     let moves: Vec<char> = lines[empty_ind + 1..].join("").chars().collect();
-    let start_pos = map.find('@').first().unwrap().clone();
+    let start_pos = *map.find('@').first().unwrap();
     map[start_pos] = '.';
     (start_pos, map, moves)
 }
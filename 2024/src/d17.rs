@@ -1,5 +1,4 @@
-use crate::DayTask;
-use std::collections::HashMap;
+use crate::{AocError, DayTask, Result};
 
 pub struct Task;
 
@@ -21,13 +20,26 @@ Register C: 0
 
 Program: 5,0,5,1,5,4";
 
+const TI4: &str = "Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0";
+
+// how many instructions `aoc-2024 trace 17` follows
+const TRACE_STEPS: usize = 100;
+
+const MNEMONICS: [&str; 8] = ["adv", "bxl", "bst", "jnz", "bxc", "out", "bdv", "cdv"];
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone)]
 struct CPU {
     a: i64,
     b: i64,
     c: i64,
     ip: usize,
     program: Vec<usize>,
-    stdout: String,
+    output: Vec<i64>,
 }
 
 impl CPU {
@@ -68,7 +80,17 @@ impl CPU {
             c,
             ip: 0, // Initialize instruction pointer to 0
             program,
-            stdout: String::new(),
+            output: vec![],
+        }
+    }
+
+    // a fresh machine with the same program, starting from another value in A
+    fn with_a(&self, a: i64) -> Self {
+        CPU {
+            a,
+            ip: 0,
+            output: vec![],
+            ..self.clone()
         }
     }
 
@@ -78,57 +100,134 @@ impl CPU {
             4 => self.a,
             5 => self.b,
             6 => self.c,
-            v @ _ => panic!("Invalid operand: {}", v),
+            v => panic!("Invalid operand: {}", v),
         }
     }
 
     fn run(&mut self) -> String {
-        while self.ip < self.program.len() {
-            let instr = self.program[self.ip];
-            let literal = self.program[self.ip + 1] as i64;
-            match instr {
-                // adv
-                0 => {
-                    self.a = self.a / 2i64.pow(self._get_combo() as u32);
-                }
-                // bxl
-                1 => {
-                    self.b = self.b ^ literal;
-                }
-                // bst
-                2 => {
-                    self.b = self._get_combo() % 8;
-                }
-                // jnz
-                3 => {
-                    if self.a != 0 {
-                        self.ip = literal as usize;
-                        continue;
-                    }
-                }
-                // bxc
-                4 => {
-                    self.b = self.b ^ self.c;
-                }
-                // out
-                5 => {
-                    self.stdout.push_str(&format!("{},", self._get_combo() % 8));
-                }
-                // bdv
-                6 => {
-                    self.b = self.a / 2i64.pow(self._get_combo() as u32);
-                }
-                // cdv
-                7 => {
-                    self.c = self.a / 2i64.pow(self._get_combo() as u32);
-                }
-                _ => {
-                    panic!("Unknown instruction: {}", instr);
+        while self.step() {}
+        self.stdout()
+    }
+
+    // runs the instruction at ip, false once the program has halted
+    fn step(&mut self) -> bool {
+        if self.ip >= self.program.len() {
+            return false;
+        }
+        let instr = self.program[self.ip];
+        let literal = self.program[self.ip + 1] as i64;
+        match instr {
+            // adv
+            0 => {
+                self.a /= 2i64.pow(self._get_combo() as u32);
+            }
+            // bxl
+            1 => {
+                self.b ^= literal;
+            }
+            // bst
+            2 => {
+                self.b = self._get_combo() % 8;
+            }
+            // jnz
+            3 => {
+                if self.a != 0 {
+                    self.ip = literal as usize;
+                    return true;
                 }
             }
-            self.ip += 2;
+            // bxc
+            4 => {
+                self.b ^= self.c;
+            }
+            // out
+            5 => {
+                self.output.push(self._get_combo() % 8);
+            }
+            // bdv
+            6 => {
+                self.b = self.a / 2i64.pow(self._get_combo() as u32);
+            }
+            // cdv
+            7 => {
+                self.c = self.a / 2i64.pow(self._get_combo() as u32);
+            }
+            _ => {
+                panic!("Unknown instruction: {}", instr);
+            }
+        }
+        self.ip += 2;
+        true
+    }
+
+    fn stdout(&self) -> String {
+        self.output.iter().map(|v| format!("{v},")).collect()
+    }
+
+    fn instruction(&self, ip: usize) -> String {
+        let (instr, operand) = (self.program[ip], self.program[ip + 1]);
+        let operand = match instr {
+            // bxl and jnz take a literal, bxc ignores its operand
+            1 | 3 => operand.to_string(),
+            4 => String::new(),
+            _ => match operand {
+                op if op <= 3 => op.to_string(),
+                4 => "A".to_string(),
+                5 => "B".to_string(),
+                6 => "C".to_string(),
+                _ => "?".to_string(),
+            },
+        };
+        format!("{} {operand}", MNEMONICS[instr])
+            .trim_end()
+            .to_string()
+    }
+
+    fn disassemble(&self) -> Vec<String> {
+        (0..self.program.len() / 2)
+            .map(|i| format!("{:>2}: {}", 2 * i, self.instruction(2 * i)))
+            .collect()
+    }
+
+    // the registers before every instruction run, at most max_steps of them
+    fn trace(&mut self, max_steps: usize) -> Vec<String> {
+        let mut trace = vec![];
+        while trace.len() < max_steps && self.ip < self.program.len() {
+            trace.push(format!(
+                "ip={:<2} A={:<12} B={:<12} C={:<12} {}",
+                self.ip,
+                self.a,
+                self.b,
+                self.c,
+                self.instruction(self.ip)
+            ));
+            self.step();
+        }
+        trace
+    }
+
+    // the programs shift A right by 3 bits on every loop and print a digit that depends on
+    // the bits still left, so the last digit printed depends only on the highest 3 bits of
+    // A; A is built from the top, 3 bits at a time, each choice keeping the tail of the
+    // output equal to the tail of the program, and the smallest digits tried first
+    fn find_quine(&self, a: i64, matched: usize) -> Option<i64> {
+        if matched == self.program.len() {
+            return Some(a);
         }
-        self.stdout.clone()
+        let tail: Vec<i64> = self.program[self.program.len() - matched - 1..]
+            .iter()
+            .map(|&v| v as i64)
+            .collect();
+        (0..8).find_map(|digit| {
+            let candidate = a * 8 + digit;
+            let mut cpu = self.with_a(candidate);
+            cpu.run();
+            if cpu.output == tail {
+                self.find_quine(candidate, matched + 1)
+            } else {
+                None
+            }
+        })
     }
 }
 
@@ -142,7 +241,7 @@ impl DayTask<String> for Task {
     }
 
     fn get_part2_test_input(&self) -> Vec<&'static str> {
-        vec![TI]
+        vec![TI4]
     }
 
     fn get_part1_test_result(&self) -> Vec<String> {
@@ -154,7 +253,7 @@ impl DayTask<String> for Task {
     }
 
     fn get_part2_test_result(&self) -> Vec<String> {
-        todo!()
+        vec![String::from("117440")]
     }
 
     fn get_part1_result(&self) -> Option<String> {
@@ -165,13 +264,28 @@ impl DayTask<String> for Task {
         None
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _is_test: bool) -> String {
+    fn run_p1(&self, lines: &Vec<String>, _is_test: bool) -> Result<String> {
+        let mut cpu = CPU::parse(lines.join("\n").as_str());
+        Ok(cpu.run())
+    }
+
+    // the program, then the registers over its first steps
+    fn trace(&self, lines: &Vec<String>) -> Result<Vec<String>> {
         let mut cpu = CPU::parse(lines.join("\n").as_str());
-        cpu.run();
-        cpu.stdout
+        let mut listing = cpu.disassemble();
+        listing.push(String::new());
+        listing.extend(cpu.trace(TRACE_STEPS));
+        Ok(listing)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _is_test: bool) -> String {
-        todo!()
+    fn run_p2(&self, lines: &Vec<String>, _is_test: bool) -> Result<String> {
+        let cpu = CPU::parse(lines.join("\n").as_str());
+        match cpu.find_quine(0, 0) {
+            Some(a) => Ok(a.to_string()),
+            None => Err(AocError::Parse(format!(
+                "no value of A makes this program print itself:\n{}",
+                cpu.disassemble().join("\n")
+            ))),
+        }
     }
 }
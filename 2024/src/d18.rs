@@ -1,8 +1,7 @@
 use crate::{
-    common::{Direction, MapVector, Point2D},
-    DayTask,
+    common::{MapVector, Point2D},
+    DayTask, Result,
 };
-use std::collections::VecDeque;
 
 pub struct Task;
 
@@ -61,17 +60,17 @@ impl DayTask<i64> for Task {
         None
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _is_test: bool) -> i64 {
+    fn run_p1(&self, lines: &Vec<String>, _is_test: bool) -> Result<i64> {
         let (size, bytes) = if _is_test {
             (Point2D { x: 7, y: 7 }, 12)
         } else {
             (Point2D { x: 71, y: 71 }, 1024)
         };
         let map = parse_input(lines, size, bytes);
-        solve(map)
+        Ok(solve(map))
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _is_test: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _is_test: bool) -> Result<i64> {
         let size = if _is_test {
             Point2D { x: 7, y: 7 }
         } else {
@@ -97,7 +96,7 @@ impl DayTask<i64> for Task {
 
         // Return the index of the blocking byte (0-based, so subtract 1)
         println!("** {} **", lines[left - 1].as_str());
-        (left - 1) as i64
+        Ok((left - 1) as i64)
     }
 }
 
@@ -109,70 +108,21 @@ fn parse_input(lines: &Vec<String>, size: Point2D<u8>, bytes: usize) -> MapVecto
         },
         0,
     );
-    let mut parsed_bytes = 0;
-    for line in lines {
-        if parsed_bytes >= bytes {
-            break;
-        }
+    for line in lines.iter().take(bytes) {
         let parts: Vec<&str> = line.split(',').collect();
         let x: usize = parts[0].parse().unwrap();
         let y: usize = parts[1].parse().unwrap();
         map[Point2D { x, y }] += 1;
-        parsed_bytes += 1;
     }
     map
 }
 
 fn solve(map: MapVector<u8>) -> i64 {
-    let start = Point2D {
-        x: 0isize,
-        y: 0isize,
-    };
     let end = Point2D {
         x: map.get_size().x as isize - 1,
         y: map.get_size().y as isize - 1,
     };
-
-    // BFS to find shortest path
-    let mut queue = VecDeque::new();
-    let mut visited = std::collections::HashSet::new();
-
-    queue.push_back((start, 0)); // (position, distance)
-    visited.insert(start);
-
-    // Directions: North, South, West, East
-    let directions = [
-        Direction::North,
-        Direction::South,
-        Direction::West,
-        Direction::East,
-    ];
-
-    while let Some((current, distance)) = queue.pop_front() {
-        if current == end {
-            return distance;
-        }
-
-        for &dir in &directions {
-            let new_pos = current.move_dir(dir, 1);
-
-            // Check bounds
-            if new_pos.x < 0
-                || new_pos.y < 0
-                || new_pos.x >= map.get_size().x as isize
-                || new_pos.y >= map.get_size().y as isize
-            {
-                continue;
-            }
-
-            // Check if not visited
-            // Check if passable (value 0)
-            if !visited.contains(&new_pos) && map[new_pos] == 0 {
-                visited.insert(new_pos);
-                queue.push_back((new_pos, distance + 1));
-            }
-        }
-    }
-
-    -1 // No path found
+    // only cells without a fallen byte are passable
+    let distances = map.bfs([Point2D::new(0, 0)], |&bytes| bytes == 0);
+    distances[end].map_or(-1, |distance| distance as i64) // -1: no path found
 }
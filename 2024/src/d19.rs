@@ -1,4 +1,4 @@
-use crate::DayTask;
+use crate::{DayTask, Result};
 
 pub struct Task;
 
@@ -42,24 +42,24 @@ impl DayTask<i64> for Task {
         None
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _is_test: bool) -> i64 {
+    fn run_p1(&self, lines: &Vec<String>, _is_test: bool) -> Result<i64> {
         let (towels, patterns) = parse_input(lines);
 
         let res = patterns
             .iter()
             .map(|pattern| check_pattern(pattern.clone(), towels.clone()).map_or(0, |_| 1))
             .sum();
-        res
+        Ok(res)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _is_test: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _is_test: bool) -> Result<i64> {
         let (towels, patterns) = parse_input(lines);
 
         let res = patterns
             .iter()
             .map(|pattern| count_pattern(pattern.clone(), towels.clone()) as i64)
             .sum();
-        res
+        Ok(res)
     }
 }
 
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-use crate::DayTask;
+use crate::{DayTask, Result};
 
 pub struct Task;
 
@@ -40,7 +40,7 @@ impl DayTask<i64> for Task {
         Some(665)
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let sets = lines
             .iter()
             .map(|l| {
@@ -50,10 +50,10 @@ impl DayTask<i64> for Task {
             })
             .map(|s| is_safe(&s))
             .sum();
-        sets
+        Ok(sets)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let sets = lines
             .iter()
             .map(|l| {
@@ -77,7 +77,7 @@ impl DayTask<i64> for Task {
                 }
             }
         }
-        count
+        Ok(count)
     }
 }
 
@@ -1,8 +1,8 @@
 use crate::{
-    common::{Direction, MapVector, Path, Point2D},
-    DayTask,
+    common::{MapVector, Point2D},
+    AocError, DayTask, Result,
 };
-use std::collections::{HashMap, VecDeque};
+use std::collections::BTreeMap;
 
 pub struct Task;
 
@@ -27,20 +27,26 @@ impl DayTask<i64> for Task {
         return 20;
     }
 
+    // the example inputs are the least number of picoseconds a cheat must save, put in
+    // front of the example track
     fn get_part1_test_input(&self) -> Vec<&'static str> {
-        vec![TI]
+        vec!["1", "20", "38", "64", "65"]
     }
 
     fn get_part2_test_input(&self) -> Vec<&'static str> {
-        vec![TI]
+        vec!["50", "72", "74", "76"]
     }
 
     fn get_part1_test_result(&self) -> Vec<i64> {
-        vec![5] // Number of shortcuts saving at least 20 steps
+        vec![44, 5, 3, 1, 0]
     }
 
     fn get_part2_test_result(&self) -> Vec<i64> {
-        todo!()
+        vec![285, 29, 7, 3]
+    }
+
+    fn get_test_data(&self, input: &str) -> Vec<String> {
+        input.lines().chain(TI.lines()).map(String::from).collect()
     }
 
     fn get_part1_result(&self) -> Option<i64> {
@@ -51,141 +57,85 @@ impl DayTask<i64> for Task {
         None
     }
 
-    fn run_p1(&self, lines: &Vec<String>, is_test: bool) -> i64 {
-        let mut map = MapVector::new(lines, |c| c);
-
-        // Find start and end positions
-        let start = map.find('S')[0];
-        let end = map.find('E')[0];
-        map[start] = '.';
-        map[end] = '.';
-
-        // BFS to find shortest path
-        let path = bfs_shortest_path(&map, start, end);
-        let original_length = (path.len() - 1) as i64;
-
-        // Analyze shortcuts with appropriate threshold
-        let at_least_saves = if is_test { 20 } else { 100 };
-        analyze_shortcuts(&map, &path, original_length, at_least_saves)
+    fn run_p1(&self, lines: &Vec<String>, is_test: bool) -> Result<i64> {
+        count_cheats(lines, is_test, 2)
     }
 
-    fn run_p2(&self, _lines: &Vec<String>, _is_test: bool) -> i64 {
-        todo!()
+    fn run_p2(&self, lines: &Vec<String>, is_test: bool) -> Result<i64> {
+        count_cheats(lines, is_test, 20)
     }
 }
 
-fn analyze_shortcuts(
-    map: &MapVector<char>,
-    path: &Path<isize>,
-    original_length: i64,
-    at_least_saves: i64,
-) -> i64 {
-    let start = path[0];
-    let end = path[path.len() - 1];
-
-    // Analyze path for wall-breaking shortcuts
-    let mut path_length_savings = HashMap::new();
-    let mut tested_walls = HashMap::new();
-
-    // For each step in the path, analyze neighboring walls
-    for step_pos in path {
-        // Check all 4 directions for walls
-        for direction in [
-            Direction::North,
-            Direction::East,
-            Direction::South,
-            Direction::West,
-        ] {
-            let wall_pos = step_pos.move_dir(direction, 1);
-
-            if !(map.is_in_map(wall_pos) && map[wall_pos] == '#') {
-                continue;
-            }
-
-            if tested_walls.contains_key(&wall_pos) {
-                continue;
-            }
-
-            // Check if behind the wall (2 steps from current position) is passable
-            let behind_wall_pos = step_pos.move_dir(direction, 2);
-            if !(map.is_in_map(behind_wall_pos) && map[behind_wall_pos] == '.') {
-                continue;
-            }
-
-            // Mark this wall as tested
-            tested_walls.insert(wall_pos, true);
-
-            // Create a modified map with this wall removed
-            let mut modified_map = map.clone();
-            modified_map[wall_pos] = '.';
-
-            // Run BFS on modified map
-            let modified_path = bfs_shortest_path(&modified_map, start, end);
-            if !modified_path.is_empty() {
-                let modified_savings = original_length - (modified_path.len() - 1) as i64;
-                *path_length_savings.entry(modified_savings).or_insert(0) += 1;
-            }
-        }
-    }
+fn count_cheats(lines: &[String], is_test: bool, radius: u32) -> Result<i64> {
+    let (at_least_saves, lines) = if is_test {
+        (lines[0].parse()?, &lines[1..])
+    } else {
+        (100, lines)
+    };
+    let track = RaceTrack::parse(lines)?;
+    Ok(track
+        .cheat_savings(radius)
+        .range(at_least_saves..)
+        .map(|(_, &count)| count as i64)
+        .sum())
+}
 
-    // Return count of shortcuts that save at least the specified number of steps
-    path_length_savings
-        .iter()
-        .filter(|&(&savings, _)| savings >= at_least_saves)
-        .map(|(_, &count)| count)
-        .sum()
+struct RaceTrack {
+    from_start: MapVector<Option<u32>>,
+    to_end: MapVector<Option<u32>>,
+    // the length of the race without cheating
+    best: u32,
 }
 
-fn bfs_shortest_path(
-    map: &MapVector<char>,
-    start: Point2D<isize>,
-    end: Point2D<isize>,
-) -> Path<isize> {
-    let mut queue = VecDeque::new();
-    let mut visited = HashMap::new();
-    let mut parent = HashMap::new();
-
-    queue.push_back(start);
-    visited.insert(start, true);
-
-    while let Some(current) = queue.pop_front() {
-        if current == end {
-            // Reconstruct path
-            let mut path = Vec::new();
-            let mut pos = current;
-            path.push(pos);
-
-            while let Some(&prev) = parent.get(&pos) {
-                path.push(prev);
-                pos = prev;
+impl RaceTrack {
+    fn parse(lines: &[String]) -> Result<Self> {
+        let map = MapVector::new(&lines.to_vec(), |c| c);
+        let (start, end) = match (map.find('S').as_slice(), map.find('E').as_slice()) {
+            ([start], [end]) => (*start, *end),
+            _ => {
+                return Err(AocError::Parse(
+                    "Expected one start and one end".to_string(),
+                ))
             }
+        };
+        let from_start = map.bfs([start], |&c| c != '#');
+        let to_end = map.bfs([end], |&c| c != '#');
+        let best = from_start[end]
+            .ok_or_else(|| AocError::Parse("The end can't be reached".to_string()))?;
+        Ok(Self {
+            from_start,
+            to_end,
+            best,
+        })
+    }
 
-            path.reverse();
-            return path;
-        }
-
-        // Check all 4 directions
-        for direction in [
-            Direction::North,
-            Direction::East,
-            Direction::South,
-            Direction::West,
-        ] {
-            let next_pos = current.move_dir(direction, 1);
-
-            // Check if position is valid and not visited
-            if map.is_in_map(next_pos) && !visited.contains_key(&next_pos) {
-                let cell = map[next_pos];
-
-                if cell == '.' {
-                    visited.insert(next_pos, true);
-                    parent.insert(next_pos, current);
-                    queue.push_back(next_pos);
+    // how many cheats save how many picoseconds, a cheat going through walls from one track
+    // position to another at most `radius` steps away; a cheat is only told apart by where
+    // it starts and ends, so each pair of positions is counted once
+    fn cheat_savings(&self, radius: u32) -> BTreeMap<u32, usize> {
+        let size = self.from_start.get_size();
+        let r = radius as isize;
+        let mut savings = BTreeMap::new();
+        for y in 0..size.y as isize {
+            for x in 0..size.x as isize {
+                let Some(before) = self.from_start[Point2D::new(x, y)] else {
+                    continue;
+                };
+                for dy in -r..=r {
+                    let reach = r - dy.abs();
+                    for dx in -reach..=reach {
+                        let Some(&Some(after)) = self.to_end.get(Point2D::new(x + dx, y + dy))
+                        else {
+                            continue;
+                        };
+                        let length = before + (dx.abs() + dy.abs()) as u32 + after;
+                        if length < self.best {
+                            *savings.entry(self.best - length).or_insert(0) += 1;
+                        }
+                    }
                 }
             }
         }
+        savings
     }
-
-    // No path found
-    Vec::new()
 }
@@ -0,0 +1,159 @@
+use crate::{
+    common::{Direction, Point2D},
+    AocError, DayTask, Result,
+};
+
+pub struct Task;
+
+const TI: &str = "029A
+980A
+179A
+456A
+379A";
+
+impl DayTask<i64> for Task {
+    fn day_no(&self) -> u8 {
+        return 21;
+    }
+
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
+    }
+
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
+    }
+
+    fn get_part1_test_result(&self) -> Vec<i64> {
+        vec![126384]
+    }
+
+    fn get_part2_test_result(&self) -> Vec<i64> {
+        vec![154115708116294]
+    }
+
+    fn get_part1_result(&self) -> Option<i64> {
+        None
+    }
+
+    fn get_part2_result(&self) -> Option<i64> {
+        None
+    }
+
+    fn run_p1(&self, lines: &Vec<String>, _is_test: bool) -> Result<i64> {
+        complexities(lines, 2)
+    }
+
+    fn run_p2(&self, lines: &Vec<String>, _is_test: bool) -> Result<i64> {
+        complexities(lines, 25)
+    }
+}
+
+// the sum over the codes of their length, as typed by us, times their numeric part
+fn complexities(lines: &[String], robots: u8) -> Result<i64> {
+    let mut sum = 0;
+    for code in lines.iter().filter(|l| !l.is_empty()) {
+        if code.chars().any(|c| Keypad::Numeric.position(c).is_none()) {
+            return Err(AocError::Parse(format!("Not a door code: {code}")));
+        }
+        let number: i64 = code.trim_end_matches('A').parse()?;
+        // the robots' directional keypads, and then ours
+        sum += sequence_cost(Keypad::Numeric, code, robots + 1) as i64 * number;
+    }
+    Ok(sum)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Keypad {
+    Numeric,
+    Directional,
+}
+
+impl Keypad {
+    // the gap is a space, no robot arm may ever point at it
+    fn rows(&self) -> &'static [&'static str] {
+        match self {
+            Keypad::Numeric => &["789", "456", "123", " 0A"],
+            Keypad::Directional => &[" ^A", "<v>"],
+        }
+    }
+
+    fn position(&self, key: char) -> Option<Point2D<isize>> {
+        self.rows()
+            .iter()
+            .enumerate()
+            .find_map(|(y, row)| row.find(key).map(|x| Point2D::new(x as isize, y as isize)))
+    }
+
+    // the moves from one key to another, all horizontal then all vertical or the other way
+    // around; zigzagging only costs more presses further down, and a way through the gap
+    // isn't allowed
+    fn moves(&self, from: char, to: char) -> Vec<Vec<Direction>> {
+        let (from, to) = (self.position(from).unwrap(), self.position(to).unwrap());
+        let gap = self.position(' ').unwrap();
+        let horizontal = if to.x > from.x {
+            Direction::East
+        } else {
+            Direction::West
+        };
+        let vertical = if to.y > from.y {
+            Direction::South
+        } else {
+            Direction::North
+        };
+        let horizontal = vec![horizontal; from.x.abs_diff(to.x)];
+        let vertical = vec![vertical; from.y.abs_diff(to.y)];
+
+        let mut candidates = vec![
+            [horizontal.clone(), vertical.clone()].concat(),
+            [vertical, horizontal].concat(),
+        ];
+        candidates.dedup();
+        candidates.retain(|moves| {
+            let mut pos = from;
+            moves.iter().all(|&dir| {
+                pos = pos.move_dir(dir, 1);
+                pos != gap
+            })
+        });
+        candidates
+    }
+}
+
+fn key(dir: Direction) -> char {
+    match dir {
+        Direction::North => '^',
+        Direction::East => '>',
+        Direction::South => 'v',
+        Direction::West => '<',
+    }
+}
+
+// our presses to type `keys` on `keypad`, with `depth` directional keypads between us and
+// it; every arm starts on A and is back on A after each press above it
+fn sequence_cost(keypad: Keypad, keys: &str, depth: u8) -> u64 {
+    let mut from = 'A';
+    let mut cost = 0;
+    for to in keys.chars() {
+        cost += press_cost(keypad, from, to, depth);
+        from = to;
+    }
+    cost
+}
+
+// our presses to move the arm over `keypad` from one key to another and press it
+#[memoize::memoize]
+fn press_cost(keypad: Keypad, from: char, to: char, depth: u8) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    keypad
+        .moves(from, to)
+        .iter()
+        .map(|moves| {
+            let keys: String = moves.iter().map(|&dir| key(dir)).chain(['A']).collect();
+            sequence_cost(Keypad::Directional, &keys, depth - 1)
+        })
+        .min()
+        .unwrap()
+}
@@ -0,0 +1,127 @@
+use crate::{DayTask, Result};
+use crossbeam_channel::unbounded;
+use std::thread;
+
+pub struct Task;
+
+const TI: &str = "1
+10
+100
+2024";
+
+const TI2: &str = "1
+2
+3
+2024";
+
+const STEPS: usize = 2000;
+const WORKERS: usize = 4;
+// a price change is in -9..=9, so four of them pack into a base 19 number
+const CHANGES: usize = 19 * 19 * 19 * 19;
+
+impl DayTask<i64> for Task {
+    fn day_no(&self) -> u8 {
+        return 22;
+    }
+
+    fn get_part1_test_input(&self) -> Vec<&'static str> {
+        vec![TI]
+    }
+
+    fn get_part2_test_input(&self) -> Vec<&'static str> {
+        vec![TI2]
+    }
+
+    fn get_part1_test_result(&self) -> Vec<i64> {
+        vec![37327623]
+    }
+
+    fn get_part2_test_result(&self) -> Vec<i64> {
+        vec![23]
+    }
+
+    fn get_part1_result(&self) -> Option<i64> {
+        None
+    }
+
+    fn get_part2_result(&self) -> Option<i64> {
+        None
+    }
+
+    fn run_p1(&self, lines: &Vec<String>, _is_test: bool) -> Result<i64> {
+        let last = |secret| (0..STEPS).fold(secret, |secret, _| next_secret(secret));
+        Ok(parse(lines)?.into_iter().map(last).sum::<u64>() as i64)
+    }
+
+    fn run_p2(&self, lines: &Vec<String>, _is_test: bool) -> Result<i64> {
+        Ok(best_bananas(&parse(lines)?) as i64)
+    }
+}
+
+fn parse(lines: &[String]) -> Result<Vec<u64>> {
+    let mut secrets = vec![];
+    for line in lines.iter().filter(|l| !l.is_empty()) {
+        secrets.push(line.trim().parse()?);
+    }
+    Ok(secrets)
+}
+
+fn next_secret(secret: u64) -> u64 {
+    let mix_prune = |secret: u64, value: u64| (secret ^ value) % 16777216;
+    let secret = mix_prune(secret, secret * 64);
+    let secret = mix_prune(secret, secret / 32);
+    mix_prune(secret, secret * 2048)
+}
+
+// selling at the first time each buyer's prices change the best way; each worker adds up the
+// bananas of its buyers for every sequence of four changes, then the totals of the workers
+// are merged
+fn best_bananas(secrets: &[u64]) -> u32 {
+    let (tx_req, rx_req) = unbounded();
+    let (tx_resp, rx_resp) = unbounded();
+    let mut bananas = vec![0; CHANGES];
+    thread::scope(|scope| {
+        for _ in 0..WORKERS {
+            let rx_req = rx_req.clone();
+            let tx_resp = tx_resp.clone();
+            scope.spawn(move || {
+                let mut totals = vec![0u32; CHANGES];
+                // the last buyer who saw each sequence, so only their first sale counts
+                let mut seen = vec![usize::MAX; CHANGES];
+                while let Ok((buyer, secret)) = rx_req.recv() {
+                    sell(buyer, secret, &mut totals, &mut seen);
+                }
+                tx_resp.send(totals).unwrap();
+            });
+        }
+        drop(tx_resp);
+
+        for buyer in secrets.iter().copied().enumerate() {
+            tx_req.send(buyer).unwrap();
+        }
+        drop(tx_req);
+
+        for totals in rx_resp {
+            for (total, worker) in bananas.iter_mut().zip(totals) {
+                *total += worker;
+            }
+        }
+    });
+    bananas.into_iter().max().unwrap_or(0)
+}
+
+// adds what one buyer pays at the first time each sequence of changes shows up
+fn sell(buyer: usize, mut secret: u64, totals: &mut [u32], seen: &mut [usize]) {
+    let mut changes = 0;
+    let mut price = secret % 10;
+    for step in 1..=STEPS {
+        secret = next_secret(secret);
+        let next_price = secret % 10;
+        changes = (changes * 19 + (next_price + 9 - price) as usize) % CHANGES;
+        price = next_price;
+        if step >= 4 && seen[changes] != buyer {
+            seen[changes] = buyer;
+            totals[changes] += price as u32;
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::DayTask;
+use crate::{DayTask, Result};
 use regex::Regex;
 
 pub struct Task;
@@ -35,8 +35,8 @@ impl DayTask<i64> for Task {
         Some(89798695)
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
-        lines
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        Ok(lines
             .iter()
             .map(|l| {
                 let regex = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
@@ -46,16 +46,16 @@ impl DayTask<i64> for Task {
                 }
                 mul
             })
-            .sum()
+            .sum())
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let regex = Regex::new(
             r"(?P<mul>mul\((?P<a>\d{1,3}),(?P<b>\d{1,3})\))|(?P<do>do\(\))|(?P<dont>don\'t\(\))",
         )
         .unwrap();
         let mut enabled = true;
-        lines
+        Ok(lines
             .iter()
             .map(|l| {
                 let mut mul: i64 = 0;
@@ -76,6 +76,6 @@ impl DayTask<i64> for Task {
                 }
                 mul
             })
-            .sum()
+            .sum())
     }
 }
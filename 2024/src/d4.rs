@@ -1,6 +1,6 @@
 use regex::Regex;
 
-use crate::DayTask;
+use crate::{DayTask, Result};
 use std::{collections::HashSet, vec};
 
 pub struct Task;
@@ -49,7 +49,7 @@ impl DayTask<i64> for Task {
         Some(1815)
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let line_len = lines.len();
         let char_lines = lines
             .iter()
@@ -108,10 +108,10 @@ impl DayTask<i64> for Task {
             .iter()
             .map(|l| xmas.find_iter(l).count())
             .sum::<usize>();
-        res as i64
+        Ok(res as i64)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let line_len = lines.len();
         let char_lines = lines
             .iter()
@@ -128,7 +128,7 @@ impl DayTask<i64> for Task {
                 let ddr_y2 = ii;
                 let ddr_x2 = oi + ii;
 
-                for (y, x) in vec![(ddr_y, ddr_x), (ddr_y2, ddr_x2)] {
+                for (y, x) in [(ddr_y, ddr_x), (ddr_y2, ddr_x2)] {
                     if y < 1 || x < 1 || y >= line_len - 1 || x >= line_len - 1 {
                         continue;
                     }
@@ -144,7 +144,7 @@ impl DayTask<i64> for Task {
                 let ddl_x: isize = line_len as isize - (oi + ii) as isize - 1;
                 let ddl_y2 = oi + ii;
                 let ddl_x2 = line_len - ii - 1;
-                for (y, x) in vec![(ddl_y, ddl_x as usize), (ddl_y2, ddl_x2)] {
+                for (y, x) in [(ddl_y, ddl_x as usize), (ddl_y2, ddl_x2)] {
                     if y < 1 || x < 1 || y >= line_len - 1 || x >= line_len - 1 {
                         continue;
                     }
@@ -158,6 +158,6 @@ impl DayTask<i64> for Task {
             }
         }
         let res = a_positions_dr.intersection(&a_positions_dl).count();
-        res as i64
+        Ok(res as i64)
     }
 }
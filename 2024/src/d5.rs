@@ -1,8 +1,5 @@
-use crate::DayTask;
-use std::{
-    collections::{HashMap, HashSet},
-    hash::Hash,
-};
+use crate::{DayTask, Result};
+use std::collections::{HashMap, HashSet};
 
 pub struct Task;
 
@@ -64,23 +61,23 @@ impl DayTask<i64> for Task {
         Some(4944)
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let (rules, reverse_rules, cases) = parse(lines);
-        cases
+        Ok(cases
             .iter()
             .map(|c| is_valid(c, &rules, &reverse_rules))
             .filter(|&b| b.is_some())
             .map(|b| b.unwrap() as i64)
-            .sum::<i64>()
+            .sum::<i64>())
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let (rules, reverse_rules, cases) = parse(lines);
-        cases
+        Ok(cases
             .iter()
             .filter(|c| is_valid(c, &rules, &reverse_rules).is_none())
             .map(|c| order_pages(c, &rules))
-            .sum::<i64>()
+            .sum::<i64>())
     }
 }
 
@@ -93,7 +90,7 @@ fn order_pages(case: &Vec<u8>, rules: &HashMap<u8, HashSet<u8>>) -> i64 {
                     .get(c)
                     .unwrap_or(&HashSet::new())
                     .intersection(&case_hs)
-                    .map(|c| *c)
+                    .copied()
                     .collect();
                 (*c, common)
             })
@@ -102,11 +99,10 @@ fn order_pages(case: &Vec<u8>, rules: &HashMap<u8, HashSet<u8>>) -> i64 {
     let mut no_incoming = case
         .iter()
         .filter(|c| !edges.values().any(|v| v.contains(c)))
-        .map(|c| *c)
+        .copied()
         .collect::<Vec<u8>>();
     let mut res: Vec<u8> = Vec::new();
-    while !no_incoming.is_empty() {
-        let n = no_incoming.pop().unwrap();
+    while let Some(n) = no_incoming.pop() {
         res.push(n);
         let next = edges.remove(&n).unwrap();
         no_incoming.extend(
@@ -117,13 +113,9 @@ fn order_pages(case: &Vec<u8>, rules: &HashMap<u8, HashSet<u8>>) -> i64 {
     res[res.len() / 2] as i64
 }
 
-fn parse(
-    lines: &Vec<String>,
-) -> (
-    HashMap<u8, HashSet<u8>>,
-    HashMap<u8, HashSet<u8>>,
-    Vec<Vec<u8>>,
-) {
+type Rules = HashMap<u8, HashSet<u8>>;
+
+fn parse(lines: &Vec<String>) -> (Rules, Rules, Vec<Vec<u8>>) {
     let (rules, reverse_rules) = lines
         .iter()
         .take_while(|l| !l.is_empty())
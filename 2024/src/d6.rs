@@ -2,7 +2,7 @@ use itertools::Itertools;
 
 use crate::{
     common::{Direction, MapVector, Point2D},
-    DayTask,
+    DayTask, Result,
 };
 use std::collections::HashSet;
 
@@ -48,12 +48,12 @@ impl DayTask<i64> for Task {
         None
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let mut map = MapVector::new(lines, |c| c);
         let starts = map.find('^');
         assert!(starts.len() == 1);
         map[starts[0]] = '.';
-        let start = Point2D::<isize>::new(starts[0].x as isize, starts[0].y as isize);
+        let start = Point2D::<isize>::new(starts[0].x, starts[0].y);
         let mut visited = HashSet::new();
         let path = find_path(&mut map, &mut visited, &start, &Direction::North);
         match path {
@@ -63,15 +63,15 @@ impl DayTask<i64> for Task {
             _ => panic!("Unexpected"),
         };
         let res = visited.iter().map(|(point, _)| point).unique().count();
-        res as i64
+        Ok(res as i64)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let mut map = MapVector::new(lines, |c| c);
         let starts = map.find('^');
         assert!(starts.len() == 1);
         map[starts[0]] = '.';
-        let mut current = Point2D::<isize>::new(starts[0].x as isize, starts[0].y as isize);
+        let mut current = Point2D::<isize>::new(starts[0].x, starts[0].y);
         let mut dir = Direction::North;
         let mut visited = HashSet::new();
         let mut obstacles: HashSet<Point2D<isize>> = HashSet::new();
@@ -80,7 +80,7 @@ impl DayTask<i64> for Task {
             let (next_pos, next_dir) = get_next_pos(&mut map, &current, &dir);
             // if we're outside map boundaries - end the loop;
             if !map.is_in_map(next_pos) {
-                return obstacles.iter().unique().count() as i64;
+                return Ok(obstacles.iter().unique().count() as i64);
             }
             map[next_pos] = '#';
             let res = find_path(&mut map, &mut visited.clone(), &current, &dir);
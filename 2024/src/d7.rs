@@ -1,5 +1,4 @@
-use crate::DayTask;
-use std::collections::HashMap;
+use crate::{DayTask, Result};
 
 pub struct Task;
 
@@ -42,24 +41,14 @@ impl DayTask<i64> for Task {
         Some(104824810233437)
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
-        let res = lines
-            .iter()
-            .map(|l| can_match(l, false))
-            .filter(|r| r.is_some())
-            .map(|r| r.unwrap())
-            .sum();
-        res
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        let res = lines.iter().filter_map(|l| can_match(l, false)).sum();
+        Ok(res)
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
-        let res = lines
-            .iter()
-            .map(|l| can_match(l, true))
-            .filter(|r| r.is_some())
-            .map(|r| r.unwrap())
-            .sum();
-        res
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        let res = lines.iter().filter_map(|l| can_match(l, true)).sum();
+        Ok(res)
     }
 }
 
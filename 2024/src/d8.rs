@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-use crate::{common::Point2D, DayTask};
+use crate::{common::Point2D, DayTask, Result};
 use std::collections::{HashMap, HashSet};
 
 pub struct Task;
@@ -47,12 +47,12 @@ impl DayTask<i64> for Task {
         Some(1339)
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
-        run_it(lines, true)
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        Ok(run_it(lines, true))
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
-        run_it(lines, false)
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
+        Ok(run_it(lines, false))
     }
 }
 
@@ -64,8 +64,7 @@ fn run_it(lines: &Vec<String>, just_first: bool) -> i64 {
         let c = antennas[antenna]
             .iter()
             .combinations(2)
-            .map(|locs| get_antinodes_in_range(locs[0], locs[1], max_x, max_y, just_first))
-            .flatten()
+            .flat_map(|locs| get_antinodes_in_range(locs[0], locs[1], max_x, max_y, just_first))
             .collect::<HashSet<Point2D<usize>>>();
         res.extend(c);
     }
@@ -76,7 +75,7 @@ fn parse(lines: &Vec<String>) -> HashMap<char, Vec<Point2D<usize>>> {
     let antennas = lines
         .iter()
         .enumerate()
-        .map(|(y, l)| {
+        .flat_map(|(y, l)| {
             l.chars()
                 .enumerate()
                 .filter_map(|(x, c)| {
@@ -88,7 +87,6 @@ fn parse(lines: &Vec<String>) -> HashMap<char, Vec<Point2D<usize>>> {
                 })
                 .collect::<Vec<(char, Point2D<usize>)>>()
         })
-        .flatten()
         .fold(
             HashMap::<char, Vec<Point2D<usize>>>::new(),
             |mut acc, (c, p)| {
@@ -1,5 +1,4 @@
-use crate::DayTask;
-use std::collections::HashMap;
+use crate::{DayTask, Result};
 
 pub struct Task;
 
@@ -34,7 +33,7 @@ impl DayTask<i64> for Task {
         None
     }
 
-    fn run_p1(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p1(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let mut sectors = parse(lines);
         let mut left = 0;
         let mut right = sectors.len() - 1;
@@ -52,10 +51,10 @@ impl DayTask<i64> for Task {
             }
             left += 1;
         }
-        checksum(sectors)
+        Ok(checksum(sectors))
     }
 
-    fn run_p2(&self, lines: &Vec<String>, _: bool) -> i64 {
+    fn run_p2(&self, lines: &Vec<String>, _: bool) -> Result<i64> {
         let mut sectors = parse(lines);
         let mut right_index = sectors.len() - 1;
         loop {
@@ -117,7 +116,7 @@ impl DayTask<i64> for Task {
                 break;
             }
         }
-        checksum(sectors)
+        Ok(checksum(sectors))
     }
 }
 
@@ -1,4 +1,3 @@
-pub mod common;
 mod d1;
 mod d10;
 mod d11;
@@ -12,8 +11,8 @@ mod d18;
 mod d19;
 mod d2;
 mod d20;
-// mod d21;
-// mod d22;
+mod d21;
+mod d22;
 // mod d23;
 // mod d24;
 // mod d25;
@@ -25,104 +24,79 @@ mod d7;
 mod d8;
 mod d9;
 
-use std::fmt::{Debug, Display};
-use std::fs::read_to_string;
-use std::time::Instant;
+use aoc_common as common;
+use aoc_common::runner::{self, register};
+use aoc_common::{AocError, DayTask, Result};
 
-pub trait DayTask<T: Debug + Display + std::cmp::Eq> {
-    fn day_no(&self) -> u8;
-
-    fn main(&self) {
-        let day = self.day_no();
-        let lines = self.read_lines(format!("d{day}.txt").as_str());
-
-        println!("[[Day {day} - part 1]]");
-        let now = Instant::now();
-        for i in 0..self.get_part1_test_input().len() {
-            assert_eq!(
-                self.run_p1(&self.get_test_data(self.get_part1_test_input()[i]), true),
-                self.get_part1_test_result()[i]
-            );
-        }
-        println!("[test: {}ms]", now.elapsed().as_millis());
-        let now = Instant::now();
-        let result1 = self.run_p1(&lines, false);
-        println!("{result1}");
-        println!("[main: {}ms]", now.elapsed().as_millis());
-        if let Some(res) = self.get_part1_result() {
-            assert_eq!(result1, res);
-        }
-
-        println!("[[Day {day} - part 2]]");
-        let now = Instant::now();
-        for i in 0..self.get_part2_test_input().len() {
-            assert_eq!(
-                self.run_p2(&self.get_test_data(self.get_part2_test_input()[i]), true),
-                self.get_part2_test_result()[i]
-            );
-        }
-        println!("[test: {}ms]", now.elapsed().as_millis());
-        let now = Instant::now();
-        let result2 = self.run_p2(&lines, false);
-        println!("{result2}");
-        println!("[main: {}ms]", now.elapsed().as_millis());
-        if let Some(res) = self.get_part2_result() {
-            assert_eq!(result2, res);
-        }
-    }
-
-    fn read_lines(&self, filename: &str) -> Vec<String> {
-        read_to_string(filename)
-            .unwrap() // panic on possible file-reading errors
-            .lines() // split the string into an iterator of string slices
-            .map(String::from) // make each slice into a string
-            .collect() // gather them together into a vector
-    }
-
-    fn get_test_data(&self, input: &str) -> Vec<String> {
-        input.lines().map(String::from).collect()
-    }
-
-    fn get_part1_test_input(&self) -> Vec<&'static str>;
-
-    fn get_part2_test_input(&self) -> Vec<&'static str>;
-
-    fn get_part1_test_result(&self) -> Vec<T>;
-
-    fn get_part2_test_result(&self) -> Vec<T>;
-
-    fn run_p1(&self, lines: &Vec<String>, is_test: bool) -> T;
-
-    fn run_p2(&self, lines: &Vec<String>, is_test: bool) -> T;
-
-    fn get_part1_result(&self) -> Option<T>;
-
-    fn get_part2_result(&self) -> Option<T>;
+fn main() {
+    let registry = runner::build_registry(vec![
+        register(d1::Task),
+        register(d2::Task),
+        register(d3::Task),
+        register(d4::Task),
+        register(d5::Task),
+        register(d6::Task),
+        register(d7::Task),
+        register(d8::Task),
+        register(d9::Task),
+        register(d10::Task),
+        register(d11::Task),
+        register(d12::Task),
+        register(d13::Task),
+        register(d14::Task),
+        register(d15::Task),
+        register(d16::Task),
+        register(d17::Task),
+        register(d18::Task),
+        register(d19::Task),
+        register(d20::Task),
+        register(d21::Task),
+        register(d22::Task),
+        // register(d23::Task),
+        // register(d24::Task),
+        // register(d25::Task),
+    ]);
+    runner::main(registry, 2024, env!("CARGO_MANIFEST_DIR"));
 }
 
-fn main() {
-    // d1::Task.main();
-    // d2::Task.main();
-    // d3::Task.main();
-    // d4::Task.main();
-    // d5::Task.main();
-    // d6::Task.main();
-    // d7::Task.main();
-    // d8::Task.main();
-    // d9::Task.main();
-    // d10::Task.main();
-    // d11::Task.main();
-    // d12::Task.main();
-    // d13::Task.main();
-    // d14::Task.main();
-    // d15::Task.main();
-    // d17::Task.main();
-    // d18::Task.main();
-    // d19::Task.main();
-    d20::Task.main();
-    // d21::Task.main();
-    // d22::Task.main();
-    // d23::Task.main();
-    // d24::Task.main();
-    // d25::Task.main();
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(
+        d1, d2, d3, d4, d5, d6, d7, d8, d9, d10, d11, d12, d13, d14, d15, d16, d17, d18, d19, d20,
+        d21, d22,
+        // d23,
+        // d24,
+        // d25,
+    );
+
+    #[test]
+    fn d17_trace() {
+        use aoc_common::DayTask;
+
+        let lines = [
+            "Register A: 729",
+            "Register B: 0",
+            "Register C: 0",
+            "",
+            "Program: 0,1,5,4,3,0",
+        ]
+        .map(String::from)
+        .to_vec();
+        let trace = super::d17::Task.trace(&lines).unwrap();
+        assert_eq!(
+            trace[..8],
+            [
+                " 0: adv 1",
+                " 2: out A",
+                " 4: jnz 0",
+                "",
+                "ip=0  A=729          B=0            C=0            adv 1",
+                "ip=2  A=364          B=0            C=0            out A",
+                "ip=4  A=364          B=0            C=0            jnz 0",
+                "ip=0  A=364          B=0            C=0            adv 1",
+            ]
+        );
+        // ten halvings get A down to 0, and then the program halts
+        assert_eq!(trace.len(), 4 + 30);
+    }
 }
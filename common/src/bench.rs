@@ -0,0 +1,232 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::runner::{catch_panic, Args, Outcome, Registry, Runnable};
+use crate::Result;
+
+// lives next to the year's inputs and is not committed
+const HISTORY_FILE: &str = ".bench-history.tsv";
+
+// all timings are in microseconds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: f64,
+    pub median: f64,
+    pub p95: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut micros: Vec<f64> = samples
+            .iter()
+            .map(|d| d.as_nanos() as f64 / 1000.0)
+            .collect();
+        micros.sort_by(|a, b| a.total_cmp(b));
+        let n = micros.len();
+        let median = if n % 2 == 1 {
+            micros[n / 2]
+        } else {
+            (micros[n / 2 - 1] + micros[n / 2]) / 2.0
+        };
+        // nearest-rank percentile
+        let p95 = micros[((n as f64 * 0.95).ceil() as usize).max(1) - 1];
+        Self {
+            runs: n,
+            min: micros[0],
+            median,
+            p95,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub timestamp: u64,
+    pub day: u8,
+    pub part: u8,
+    pub stats: Stats,
+}
+
+impl Record {
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{:.1}\t{:.1}\t{:.1}",
+            self.timestamp,
+            self.day,
+            self.part,
+            self.stats.runs,
+            self.stats.min,
+            self.stats.median,
+            self.stats.p95
+        )
+    }
+
+    fn parse(line: &str) -> Option<Self> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        if fields.len() != 7 {
+            return None;
+        }
+        Some(Self {
+            timestamp: fields[0].parse().ok()?,
+            day: fields[1].parse().ok()?,
+            part: fields[2].parse().ok()?,
+            stats: Stats {
+                runs: fields[3].parse().ok()?,
+                min: fields[4].parse().ok()?,
+                median: fields[5].parse().ok()?,
+                p95: fields[6].parse().ok()?,
+            },
+        })
+    }
+}
+
+pub fn load_history(path: &Path) -> Vec<Record> {
+    fs::read_to_string(path)
+        .map(|content| content.lines().filter_map(Record::parse).collect())
+        .unwrap_or_default()
+}
+
+fn append_history(path: &Path, records: &[Record]) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    for record in records {
+        writeln!(file, "{}", record.to_line())?;
+    }
+    Ok(())
+}
+
+pub fn run(
+    registry: &Registry,
+    args: &Args,
+    input_dir: &Path,
+    runs: usize,
+    warmup: usize,
+) -> std::result::Result<Vec<Outcome>, String> {
+    // timings of a custom input can't be compared with the history of the real one
+    let history_path = args.input.is_none().then(|| input_dir.join(HISTORY_FILE));
+    let history = history_path
+        .as_deref()
+        .map(load_history)
+        .unwrap_or_default();
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    let mut records = vec![];
+    let mut outcomes = vec![];
+    for day in args.select_days(registry)? {
+        let task = registry[&day].as_ref();
+        let input = args.input_path(input_dir, day);
+        let lines = match task.read_lines(&input.to_string_lossy()) {
+            Ok(lines) => lines,
+            Err(err) => {
+                outcomes.push(Outcome {
+                    day,
+                    part: None,
+                    result: Err(err),
+                    elapsed: None,
+                });
+                continue;
+            }
+        };
+        for part in args.parts() {
+            let result = catch_panic(|| bench_part(task, part, &lines, runs, warmup));
+            let result = result.map(|(answer, stats)| {
+                let previous = history
+                    .iter()
+                    .rev()
+                    .find(|r| r.day == day && r.part == part);
+                print_stats(day, part, &stats, previous.map(|r| &r.stats));
+                records.push(Record {
+                    timestamp,
+                    day,
+                    part,
+                    stats,
+                });
+                answer
+            });
+            outcomes.push(Outcome {
+                day,
+                part: Some(part),
+                result,
+                elapsed: None,
+            });
+        }
+    }
+    if let Some(path) = history_path {
+        if let Err(err) = append_history(&path, &records) {
+            eprintln!("Cannot save bench results to {}: {err}", path.display());
+        }
+    }
+    Ok(outcomes)
+}
+
+fn bench_part(
+    task: &dyn Runnable,
+    part: u8,
+    lines: &Vec<String>,
+    runs: usize,
+    warmup: usize,
+) -> Result<(String, Stats)> {
+    // the first untimed run also checks the answer, so a broken day is never timed
+    let answer = task.solve(part, lines)?;
+    for _ in 1..warmup {
+        task.solve(part, lines)?;
+    }
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let now = Instant::now();
+        task.solve(part, lines)?;
+        samples.push(now.elapsed());
+    }
+    Ok((answer, Stats::from_samples(&samples)))
+}
+
+fn print_stats(day: u8, part: u8, stats: &Stats, previous: Option<&Stats>) {
+    let delta = match previous {
+        Some(previous) => format!(
+            "median {:+.1}% vs previous ({:.1}µs)",
+            (stats.median - previous.median) / previous.median * 100.0,
+            previous.median
+        ),
+        None => "no previous run".to_string(),
+    };
+    println!(
+        "Day {day:>2} part {part}: min {:.1}µs  median {:.1}µs  p95 {:.1}µs  ({} runs)  {delta}",
+        stats.min, stats.median, stats.p95, stats.runs
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_from_samples() {
+        let samples: Vec<Duration> = (1..=20).rev().map(Duration::from_micros).collect();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.runs, 20);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 10.5);
+        assert_eq!(stats.p95, 19.0);
+    }
+
+    #[test]
+    fn record_round_trip() {
+        let record = Record {
+            timestamp: 1734000000,
+            day: 20,
+            part: 2,
+            stats: Stats {
+                runs: 10,
+                min: 812.3,
+                median: 830.1,
+                p95: 901.2,
+            },
+        };
+        assert_eq!(Record::parse(&record.to_line()), Some(record));
+        assert_eq!(Record::parse("garbage"), None);
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use num::{BigInt, Integer, ToPrimitive, Zero};
+
+use crate::math::crt;
+
+// a sequence of states that repeats: the state at `start + period` is the one at `start`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    // the step before the first repeat that has the same state as step n
+    pub fn index_of(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+// hashing every state; also returns them, so any later step is a lookup
+pub fn find_cycle<S: Clone + Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+) -> (Cycle, Vec<S>) {
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = initial;
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            let period = states.len() - start;
+            return (Cycle { start, period }, states);
+        }
+        let next = step(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = next;
+    }
+}
+
+// Brent's algorithm keeps only two states, for when they are too big to store all of them
+pub fn brent<S: Clone + PartialEq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // the hare looks for the period within windows of doubling size
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // with the hare a period ahead, they first meet where the cycle starts
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, period }
+}
+
+// the state after n steps, only running the steps until the first repeat
+pub fn state_at<S: Clone + Eq + Hash>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = initial;
+    while states.len() < n {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                period: states.len() - start,
+            };
+            return states.swap_remove(cycle.index_of(n));
+        }
+        let next = step(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = next;
+    }
+    state
+}
+
+// an event seen at `first` and then again every `period` steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Periodic {
+    pub first: u64,
+    pub period: u64,
+}
+
+impl Periodic {
+    // from the observed times of an event, None unless they are evenly spaced
+    pub fn from_times(times: &[u64]) -> Option<Self> {
+        let period = times.get(1)?.checked_sub(times[0])?;
+        if period == 0 || times.windows(2).any(|w| w[1] - w[0] != period) {
+            return None;
+        }
+        Some(Self {
+            first: times[0],
+            period,
+        })
+    }
+}
+
+// the first step at which all the events happen together, by the Chinese remainder theorem,
+// so the events don't need to first happen after exactly one period
+pub fn align(events: &[Periodic]) -> Option<u64> {
+    let congruences: Vec<(BigInt, BigInt)> = events
+        .iter()
+        .map(|e| (e.first.into(), e.period.into()))
+        .collect();
+    let (residue, modulus) = crt(&congruences)?;
+    // a time in the right residue class, but not before any of the events first happens
+    let earliest = BigInt::from(events.iter().map(|e| e.first).max().unwrap_or(0));
+    let behind = (earliest - &residue).max(BigInt::zero());
+    let time = residue + behind.div_ceil(&modulus) * modulus;
+    time.to_u64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, then 3, 4, 5, 6 over and over
+    fn step(n: &u32) -> u32 {
+        if *n == 6 {
+            3
+        } else {
+            n + 1
+        }
+    }
+
+    #[test]
+    fn hashing_and_brent_agree() {
+        let expected = Cycle {
+            start: 3,
+            period: 4,
+        };
+        let (cycle, states) = find_cycle(0, step);
+        assert_eq!(cycle, expected);
+        assert_eq!(states, vec![0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(brent(0, step), expected);
+        assert_eq!(
+            brent(3, step),
+            Cycle {
+                start: 0,
+                period: 4
+            }
+        );
+        assert_eq!(cycle.index_of(1_000_000_001), 5);
+    }
+
+    #[test]
+    fn far_future_state() {
+        assert_eq!(state_at(0, step, 2), 2);
+        assert_eq!(state_at(0, step, 7), 3);
+        assert_eq!(state_at(0, step, 1_000_000_001), 5);
+    }
+
+    #[test]
+    fn events_with_offsets() {
+        assert_eq!(
+            Periodic::from_times(&[3, 8, 13]),
+            Some(Periodic {
+                first: 3,
+                period: 5
+            })
+        );
+        assert_eq!(Periodic::from_times(&[3, 8, 14]), None);
+        assert_eq!(Periodic::from_times(&[3]), None);
+
+        let every = |first, period| Periodic { first, period };
+        // the offsets are the periods: just the lcm
+        assert_eq!(align(&[every(4, 4), every(6, 6)]), Some(12));
+        // x = 2 (mod 3), x = 3 (mod 5), x = 2 (mod 7)
+        assert_eq!(align(&[every(2, 3), every(3, 5), every(2, 7)]), Some(23));
+        // shared factors, and not before both events started
+        assert_eq!(align(&[every(10, 4), every(0, 6)]), Some(18));
+        assert_eq!(align(&[every(1, 4), every(0, 6)]), None);
+    }
+}
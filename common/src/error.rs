@@ -0,0 +1,74 @@
+use std::{error, fmt, io, num};
+
+#[derive(Debug)]
+pub enum AocError {
+    MissingInput {
+        path: String,
+        source: io::Error,
+    },
+    Parse(String),
+    TestMismatch {
+        part: u8,
+        case: usize,
+        expected: String,
+        actual: String,
+    },
+    AnswerMismatch {
+        part: u8,
+        expected: String,
+        actual: String,
+    },
+    NotImplemented,
+    // a day still panicked (usually an unwrap() in its parser), the runner caught it
+    Panic(String),
+}
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::MissingInput { path, source } => {
+                write!(f, "cannot read input {path}: {source}")
+            }
+            AocError::Parse(msg) => write!(f, "parse error: {msg}"),
+            AocError::TestMismatch {
+                part,
+                case,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "part {part} example {case}: expected {expected}, got {actual}"
+            ),
+            AocError::AnswerMismatch {
+                part,
+                expected,
+                actual,
+            } => write!(f, "part {part} answer: expected {expected}, got {actual}"),
+            AocError::NotImplemented => write!(f, "not implemented"),
+            AocError::Panic(msg) => write!(f, "panicked: {msg}"),
+        }
+    }
+}
+
+impl error::Error for AocError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            AocError::MissingInput { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<num::ParseIntError> for AocError {
+    fn from(err: num::ParseIntError) -> Self {
+        AocError::Parse(err.to_string())
+    }
+}
+
+impl From<num::ParseFloatError> for AocError {
+    fn from(err: num::ParseFloatError) -> Self {
+        AocError::Parse(err.to_string())
+    }
+}
@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::graph::Graph;
+use crate::Point2D;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Dot,
+    Mermaid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Round,
+    Box,
+    Diamond,
+    Circle,
+}
+
+// a graph ready to be written out for Graphviz or Mermaid, so a day can draw whatever it
+// parsed without another toolchain re-parsing the input
+#[derive(Debug, Clone)]
+pub struct Drawing {
+    directed: bool,
+    names: Vec<String>,
+    shapes: Vec<Shape>,
+    ids: HashMap<String, usize>,
+    edges: Vec<(usize, usize, Option<String>)>,
+}
+
+impl Drawing {
+    pub fn new(directed: bool) -> Self {
+        Self {
+            directed,
+            names: vec![],
+            shapes: vec![],
+            ids: HashMap::new(),
+            edges: vec![],
+        }
+    }
+
+    // every edge of a graph, labelled with its weight when `label` gives one
+    pub fn from_graph<W: Clone>(graph: &Graph<W>, label: impl Fn(&W) -> Option<String>) -> Self {
+        let mut drawing = Self::new(graph.is_directed());
+        for id in 0..graph.len() {
+            drawing.node(graph.name(id));
+        }
+        for id in 0..graph.len() {
+            // an undirected edge is stored on both of its ends, a loop twice on the same one
+            let mut skip_loop = false;
+            for (to, weight) in graph.edges(id) {
+                if !graph.is_directed() && *to <= id {
+                    if *to < id || skip_loop {
+                        skip_loop = false;
+                        continue;
+                    }
+                    skip_loop = true;
+                }
+                drawing.edges.push((id, *to, label(weight)));
+            }
+        }
+        drawing
+    }
+
+    // nodes are drawn round unless given another shape
+    pub fn node(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        self.names.push(name.to_string());
+        self.shapes.push(Shape::Round);
+        self.ids.insert(name.to_string(), self.names.len() - 1);
+        self.names.len() - 1
+    }
+
+    pub fn set_shape(&mut self, name: &str, shape: Shape) {
+        let id = self.node(name);
+        self.shapes[id] = shape;
+    }
+
+    pub fn edge(&mut self, from: &str, to: &str, label: Option<String>) {
+        let (from, to) = (self.node(from), self.node(to));
+        self.edges.push((from, to, label));
+    }
+
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Dot => self.to_dot(),
+            Format::Mermaid => self.to_mermaid(),
+        }
+    }
+
+    fn to_dot(&self) -> String {
+        let (kind, arrow) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        let mut out = format!("{kind} {{\n");
+        for (name, shape) in self.names.iter().zip(&self.shapes) {
+            let shape = match shape {
+                Shape::Round => "ellipse",
+                Shape::Box => "box",
+                Shape::Diamond => "diamond",
+                Shape::Circle => "circle",
+            };
+            writeln!(out, "  {} [shape={shape}];", quote(name, Format::Dot)).unwrap();
+        }
+        for (from, to, label) in &self.edges {
+            let label = label.as_ref().map_or(String::new(), |l| {
+                format!(" [label={}]", quote(l, Format::Dot))
+            });
+            let (from, to) = (
+                quote(&self.names[*from], Format::Dot),
+                quote(&self.names[*to], Format::Dot),
+            );
+            writeln!(out, "  {from} {arrow} {to}{label};").unwrap();
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    // Mermaid ids can't hold most punctuation, so the nodes are numbered and the names are labels
+    fn to_mermaid(&self) -> String {
+        let arrow = if self.directed { "-->" } else { "---" };
+        let mut out = "flowchart TD\n".to_string();
+        for (id, (name, shape)) in self.names.iter().zip(&self.shapes).enumerate() {
+            let name = quote(name, Format::Mermaid);
+            let node = match shape {
+                Shape::Round => format!("({name})"),
+                Shape::Box => format!("[{name}]"),
+                Shape::Diamond => format!("{{{name}}}"),
+                Shape::Circle => format!("(({name}))"),
+            };
+            writeln!(out, "  n{id}{node}").unwrap();
+        }
+        for (from, to, label) in &self.edges {
+            let label = label.as_ref().map_or(String::new(), |l| {
+                format!("|{}|", quote(l, Format::Mermaid))
+            });
+            writeln!(out, "  n{from} {arrow}{label} n{to}").unwrap();
+        }
+        out
+    }
+}
+
+pub fn point_name(p: Point2D<isize>) -> String {
+    format!("{},{}", p.x, p.y)
+}
+
+// DOT escapes quotes with a backslash, Mermaid only knows its entity codes
+fn quote(s: &str, format: Format) -> String {
+    let s = match format {
+        Format::Dot => s.replace('\\', "\\\\").replace('"', "\\\""),
+        Format::Mermaid => s.replace('"', "#quot;"),
+    };
+    format!("\"{s}\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn modules() -> Drawing {
+        let mut graph = Graph::directed();
+        graph.add_edge("broadcaster", "a", 1);
+        graph.add_edge("a", "inv", 2);
+        let mut drawing = Drawing::from_graph(&graph, |w| Some(w.to_string()));
+        drawing.set_shape("inv", Shape::Diamond);
+        drawing
+    }
+
+    #[test]
+    fn dot() {
+        assert_eq!(
+            modules().render(Format::Dot),
+            "digraph {
+  \"broadcaster\" [shape=ellipse];
+  \"a\" [shape=ellipse];
+  \"inv\" [shape=diamond];
+  \"broadcaster\" -> \"a\" [label=\"1\"];
+  \"a\" -> \"inv\" [label=\"2\"];
+}
+"
+        );
+    }
+
+    #[test]
+    fn mermaid() {
+        assert_eq!(
+            modules().render(Format::Mermaid),
+            "flowchart TD
+  n0(\"broadcaster\")
+  n1(\"a\")
+  n2{\"inv\"}
+  n0 -->|\"1\"| n1
+  n1 -->|\"2\"| n2
+"
+        );
+    }
+
+    #[test]
+    fn undirected_edges_are_drawn_once() {
+        let mut graph = Graph::undirected();
+        for (a, b) in [("a", "b"), ("b", "c"), ("c", "c"), ("a", "b")] {
+            graph.add_edge(a, b, ());
+        }
+        let dot = Drawing::from_graph(&graph, |_| None).render(Format::Dot);
+        assert_eq!(dot.matches(" -- ").count(), 4);
+        assert!(dot.starts_with("graph {"));
+    }
+
+    #[test]
+    fn quotes_are_escaped_per_format() {
+        let mut drawing = Drawing::new(true);
+        drawing.edge("say \"hi\"", "c:\\", None);
+        let dot = drawing.render(Format::Dot);
+        assert!(dot.contains("  \"say \\\"hi\\\"\" -> \"c:\\\\\";"));
+        let mermaid = drawing.render(Format::Mermaid);
+        assert!(mermaid.contains("  n0(\"say #quot;hi#quot;\")"));
+        assert!(mermaid.contains("  n1(\"c:\\\")"));
+    }
+}
@@ -0,0 +1,351 @@
+use num::rational::Ratio;
+use num::{Integer, Signed, Zero};
+
+use crate::{Direction, Path, Point2D};
+
+// exact, as long as the products of two coordinates fit in an i128
+pub type Rational = Ratio<i128>;
+
+// the points origin + t * direction, t being a time for a moving object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line {
+    pub origin: Point2D<isize>,
+    pub direction: Point2D<isize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Intersection {
+    // the lines meet once, at t along the first and u along the second
+    Point {
+        t: Rational,
+        u: Rational,
+        at: Point2D<Rational>,
+    },
+    Parallel,
+    Collinear,
+}
+
+impl Line {
+    pub fn new(origin: Point2D<isize>, direction: Point2D<isize>) -> Self {
+        Self { origin, direction }
+    }
+
+    pub fn through(from: Point2D<isize>, to: Point2D<isize>) -> Self {
+        Self::new(from, Point2D::new(to.x - from.x, to.y - from.y))
+    }
+
+    pub fn at(&self, t: Rational) -> Point2D<Rational> {
+        let [ox, oy, dx, dy] = [
+            self.origin.x,
+            self.origin.y,
+            self.direction.x,
+            self.direction.y,
+        ]
+        .map(|v| Rational::from_integer(v as i128));
+        Point2D::new(ox + dx * t, oy + dy * t)
+    }
+
+    pub fn intersect(&self, other: &Line) -> Intersection {
+        let d1 = wide(self.direction);
+        let d2 = wide(other.direction);
+        let offset = [
+            other.origin.x as i128 - self.origin.x as i128,
+            other.origin.y as i128 - self.origin.y as i128,
+        ];
+        let denominator = cross(d1, d2);
+        if denominator.is_zero() {
+            return if cross(offset, d1).is_zero() {
+                Intersection::Collinear
+            } else {
+                Intersection::Parallel
+            };
+        }
+        let t = Rational::new(cross(offset, d2), denominator);
+        let u = Rational::new(cross(offset, d1), denominator);
+        Intersection::Point {
+            t,
+            u,
+            at: self.at(t),
+        }
+    }
+
+    // where both objects will be at some point, not necessarily at the same time
+    pub fn crossing_ahead(&self, other: &Line) -> Option<Point2D<Rational>> {
+        match self.intersect(other) {
+            Intersection::Point { t, u, at } if !t.is_negative() && !u.is_negative() => Some(at),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub start: Point2D<isize>,
+    pub end: Point2D<isize>,
+}
+
+impl Segment {
+    pub fn new(start: Point2D<isize>, end: Point2D<isize>) -> Self {
+        Self { start, end }
+    }
+
+    // any shared point, end points and overlaps of collinear segments included
+    pub fn intersects(&self, other: &Segment) -> bool {
+        let (a, b) = (
+            Line::through(self.start, self.end),
+            Line::through(other.start, other.end),
+        );
+        let unit = 0.into()..=1.into();
+        match a.intersect(&b) {
+            Intersection::Point { t, u, .. } => unit.contains(&t) && unit.contains(&u),
+            Intersection::Parallel => false,
+            // one of them is a single point, or they run along the same line
+            Intersection::Collinear => {
+                let project = |p: Point2D<isize>| {
+                    let d = wide(a.direction);
+                    let v = [
+                        p.x as i128 - a.origin.x as i128,
+                        p.y as i128 - a.origin.y as i128,
+                    ];
+                    v[0] * d[0] + v[1] * d[1]
+                };
+                let length = project(self.end);
+                if length.is_zero() {
+                    return other.contains(self.start);
+                }
+                let (s, e) = (project(other.start), project(other.end));
+                s.min(e) <= length && s.max(e) >= 0
+            }
+        }
+    }
+
+    pub fn contains(&self, p: Point2D<isize>) -> bool {
+        let d = wide(Line::through(self.start, self.end).direction);
+        let v = [
+            p.x as i128 - self.start.x as i128,
+            p.y as i128 - self.start.y as i128,
+        ];
+        cross(d, v).is_zero()
+            && p.x >= self.start.x.min(self.end.x)
+            && p.x <= self.start.x.max(self.end.x)
+            && p.y >= self.start.y.min(self.end.y)
+            && p.y <= self.start.y.max(self.end.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    // as drawn on a grid, with y growing downwards
+    Clockwise,
+    CounterClockwise,
+}
+
+// a closed polygon on the lattice, through its vertices in order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Point2D<isize>>,
+}
+
+impl Polygon {
+    // the last vertex may repeat the first one or not
+    pub fn new(mut vertices: Path<isize>) -> Self {
+        if vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        Self { vertices }
+    }
+
+    // dug or walked from `start`, one straight run at a time
+    pub fn from_moves(
+        start: Point2D<isize>,
+        moves: impl IntoIterator<Item = (Direction, isize)>,
+    ) -> Self {
+        let mut vertices = vec![start];
+        for (dir, steps) in moves {
+            vertices.push(vertices.last().unwrap().move_dir(dir, steps));
+        }
+        Self::new(vertices)
+    }
+
+    pub fn vertices(&self) -> &[Point2D<isize>] {
+        &self.vertices
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Point2D<isize>, Point2D<isize>)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    // the shoelace formula, positive when clockwise on a grid; twice the area keeps it whole
+    pub fn signed_double_area(&self) -> i128 {
+        self.edges().map(|(a, b)| cross(wide(a), wide(b))).sum()
+    }
+
+    pub fn double_area(&self) -> i128 {
+        self.signed_double_area().abs()
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        if self.signed_double_area() >= 0 {
+            Orientation::Clockwise
+        } else {
+            Orientation::CounterClockwise
+        }
+    }
+
+    // lattice points on the edges, the perimeter when every edge is horizontal or vertical
+    pub fn boundary_points(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| (a.x.abs_diff(b.x) as i128).gcd(&(a.y.abs_diff(b.y) as i128)))
+            .sum()
+    }
+
+    // lattice points strictly inside, by Pick's theorem: A = i + b / 2 - 1
+    pub fn interior_points(&self) -> i128 {
+        (self.double_area() - self.boundary_points() + 2) / 2
+    }
+
+    // every lattice point covered, like every cube of a trench dug along the edges
+    pub fn lattice_points(&self) -> i128 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    pub fn on_boundary(&self, p: Point2D<isize>) -> bool {
+        self.edges().any(|(a, b)| Segment::new(a, b).contains(p))
+    }
+
+    // strictly inside, by counting the edges crossed by a ray going right from p
+    pub fn contains(&self, p: Point2D<isize>) -> bool {
+        if self.on_boundary(p) {
+            return false;
+        }
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            if (a.y > p.y) != (b.y > p.y) {
+                let d = wide(Line::through(a, b).direction);
+                let v = [p.x as i128 - a.x as i128, p.y as i128 - a.y as i128];
+                // the edge crosses the ray right of p when this has the sign of the edge's dy
+                if (cross(d, v) > 0) == (d[1] > 0) {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+}
+
+fn wide(p: Point2D<isize>) -> [i128; 2] {
+    [p.x as i128, p.y as i128]
+}
+
+fn cross(a: [i128; 2], b: [i128; 2]) -> i128 {
+    a[0] * b[1] - a[1] * b[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(x: isize, y: isize) -> Point2D<isize> {
+        Point2D::new(x, y)
+    }
+
+    #[test]
+    fn line_intersections() {
+        let a = Line::new(p(19, 13), p(-2, 1));
+        let b = Line::new(p(18, 19), p(-1, -1));
+        let at = a.crossing_ahead(&b).unwrap();
+        // 14.333, 15.333 in the 2023 day 24 example
+        assert_eq!(at, Point2D::new(Rational::new(43, 3), Rational::new(46, 3)));
+        assert!(at.within(
+            &Point2D::new(7.into(), 7.into()),
+            &Point2D::new(27.into(), 27.into())
+        ));
+        // crossed in the past of the second one
+        let c = Line::new(p(20, 19), p(1, -5));
+        assert!(matches!(a.intersect(&c), Intersection::Point { .. }));
+        assert_eq!(a.crossing_ahead(&c), None);
+
+        // vertical lines are no special case
+        let vertical = Line::new(p(5, 0), p(0, 3));
+        let horizontal = Line::new(p(0, 7), p(2, 0));
+        assert_eq!(
+            vertical.crossing_ahead(&horizontal),
+            Some(Point2D::new(5.into(), 7.into()))
+        );
+        assert_eq!(
+            vertical.intersect(&Line::new(p(6, 0), p(0, -1))),
+            Intersection::Parallel
+        );
+        assert_eq!(
+            vertical.intersect(&Line::new(p(5, 9), p(0, -1))),
+            Intersection::Collinear
+        );
+
+        // would lose precision with f64
+        let far = 200_000_000_000_001;
+        let a = Line::new(p(far, 0), p(1, 1));
+        let b = Line::new(p(far + 2, 0), p(-1, 1));
+        assert_eq!(
+            a.crossing_ahead(&b),
+            Some(Point2D::new((far as i128 + 1).into(), 1.into()))
+        );
+    }
+
+    #[test]
+    fn segment_intersections() {
+        let s = Segment::new(p(0, 0), p(4, 4));
+        assert!(s.intersects(&Segment::new(p(0, 4), p(4, 0))));
+        assert!(s.intersects(&Segment::new(p(4, 4), p(6, 0))));
+        assert!(!s.intersects(&Segment::new(p(5, 5), p(6, 0))));
+        assert!(s.intersects(&Segment::new(p(3, 3), p(9, 9))));
+        assert!(!s.intersects(&Segment::new(p(5, 5), p(9, 9))));
+        assert!(!s.intersects(&Segment::new(p(0, 1), p(4, 5))));
+        assert!(Segment::new(p(2, 2), p(2, 2)).intersects(&s));
+        assert!(s.contains(p(1, 1)));
+        assert!(!s.contains(p(1, 2)));
+    }
+
+    #[test]
+    fn polygons() {
+        use Direction::*;
+        // the 2023 day 18 example trench
+        let moves = [
+            (East, 6),
+            (South, 5),
+            (West, 2),
+            (South, 2),
+            (East, 2),
+            (South, 2),
+            (West, 5),
+            (North, 2),
+            (West, 1),
+            (North, 2),
+            (East, 2),
+            (North, 3),
+            (West, 2),
+            (North, 2),
+        ];
+        let trench = Polygon::from_moves(p(0, 0), moves);
+        assert_eq!(trench.vertices().len(), 14);
+        assert_eq!(trench.double_area(), 84);
+        assert_eq!(trench.boundary_points(), 38);
+        assert_eq!(trench.lattice_points(), 62);
+        assert_eq!(trench.orientation(), Orientation::Clockwise);
+
+        let triangle = Polygon::new(vec![p(0, 0), p(0, 4), p(4, 0), p(0, 0)]);
+        assert_eq!(triangle.signed_double_area(), -16);
+        assert_eq!(triangle.orientation(), Orientation::CounterClockwise);
+        // 4 points on each short side and 5 on the diagonal, 3 inside
+        assert_eq!(triangle.boundary_points(), 12);
+        assert_eq!(triangle.interior_points(), 3);
+        let inside: Vec<_> = (0..=4)
+            .flat_map(|y| (0..=4).map(move |x| p(x, y)))
+            .filter(|&q| triangle.contains(q))
+            .collect();
+        assert_eq!(inside, vec![p(1, 1), p(2, 1), p(1, 2)]);
+        assert!(triangle.on_boundary(p(2, 2)));
+        assert!(!triangle.contains(p(2, 2)));
+        assert!(!triangle.contains(p(-1, 1)));
+    }
+}
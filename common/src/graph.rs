@@ -0,0 +1,423 @@
+use std::collections::{HashMap, VecDeque};
+
+// Node names are interned to dense ids in insertion order, so the days can work on plain
+// indices; every node keeps its out-edges in the order they were added.
+#[derive(Debug, Clone)]
+pub struct Graph<W = ()> {
+    directed: bool,
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    edges: Vec<Vec<(usize, W)>>,
+    // incoming edges, only kept apart for a directed graph
+    reverse: Vec<Vec<(usize, W)>>,
+}
+
+impl<W: Clone> Graph<W> {
+    pub fn directed() -> Self {
+        Self::new(true)
+    }
+
+    pub fn undirected() -> Self {
+        Self::new(false)
+    }
+
+    fn new(directed: bool) -> Self {
+        Self {
+            directed,
+            names: vec![],
+            ids: HashMap::new(),
+            edges: vec![],
+            reverse: vec![],
+        }
+    }
+
+    // id of a node, added without edges if it's new
+    pub fn id(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.edges.push(vec![]);
+        self.reverse.push(vec![]);
+        id
+    }
+
+    pub fn get_id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn add_edge(&mut self, from: &str, to: &str, weight: W) -> (usize, usize) {
+        let (from, to) = (self.id(from), self.id(to));
+        self.add_edge_ids(from, to, weight);
+        (from, to)
+    }
+
+    pub fn add_edge_ids(&mut self, from: usize, to: usize, weight: W) {
+        self.edges[from].push((to, weight.clone()));
+        if self.directed {
+            self.reverse[to].push((from, weight));
+        } else {
+            self.edges[to].push((from, weight));
+        }
+    }
+
+    // outgoing edges with their weights; both directions of an undirected graph
+    pub fn edges(&self, id: usize) -> &[(usize, W)] {
+        &self.edges[id]
+    }
+
+    // incoming edges with their weights, the same as `edges` for an undirected graph
+    pub fn reverse_edges(&self, id: usize) -> &[(usize, W)] {
+        if self.directed {
+            &self.reverse[id]
+        } else {
+            &self.edges[id]
+        }
+    }
+
+    pub fn successors(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        self.edges(id).iter().map(|&(next, _)| next)
+    }
+
+    pub fn predecessors(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        self.reverse_edges(id).iter().map(|&(prev, _)| prev)
+    }
+
+    pub fn out_degree(&self, id: usize) -> usize {
+        self.edges(id).len()
+    }
+
+    pub fn in_degree(&self, id: usize) -> usize {
+        self.reverse_edges(id).len()
+    }
+
+    pub fn bfs(&self, start: usize) -> Traversal<'_, W> {
+        Traversal::new(self, start, false)
+    }
+
+    pub fn dfs(&self, start: usize) -> Traversal<'_, W> {
+        Traversal::new(self, start, true)
+    }
+
+    // node ids of every connected component, ignoring edge directions
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut seen = vec![false; self.len()];
+        let mut components = vec![];
+        for start in 0..self.len() {
+            if seen[start] {
+                continue;
+            }
+            seen[start] = true;
+            let mut component = vec![];
+            let mut to_visit = vec![start];
+            while let Some(id) = to_visit.pop() {
+                component.push(id);
+                for next in self.successors(id).chain(self.predecessors(id)) {
+                    if !seen[next] {
+                        seen[next] = true;
+                        to_visit.push(next);
+                    }
+                }
+            }
+            components.push(component);
+        }
+        components
+    }
+
+    pub fn min_cut(&self) -> Option<Cut> {
+        assert!(!self.directed, "A cut needs an undirected graph");
+        let adjacency: Vec<Vec<usize>> = (0..self.len())
+            .map(|id| self.successors(id).collect())
+            .collect();
+        min_cut(&adjacency)
+    }
+}
+
+// the nodes reachable from a start, in breadth-first or depth-first (preorder) order
+pub struct Traversal<'a, W> {
+    graph: &'a Graph<W>,
+    seen: Vec<bool>,
+    to_visit: VecDeque<usize>,
+    depth_first: bool,
+}
+
+impl<'a, W: Clone> Traversal<'a, W> {
+    fn new(graph: &'a Graph<W>, start: usize, depth_first: bool) -> Self {
+        let mut seen = vec![false; graph.len()];
+        // breadth-first marks nodes when they're queued, depth-first when they're visited
+        seen[start] = !depth_first;
+        Self {
+            graph,
+            seen,
+            to_visit: VecDeque::from([start]),
+            depth_first,
+        }
+    }
+}
+
+impl<W: Clone> Iterator for Traversal<'_, W> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            let id = if self.depth_first {
+                self.to_visit.pop_back()?
+            } else {
+                self.to_visit.pop_front()?
+            };
+            if self.depth_first {
+                if self.seen[id] {
+                    continue;
+                }
+                self.seen[id] = true;
+            }
+            // pushed in reverse for a depth-first walk to follow the edges in their order
+            let next: Vec<usize> = self.graph.successors(id).collect();
+            let next: Box<dyn Iterator<Item = usize>> = if self.depth_first {
+                Box::new(next.into_iter().rev())
+            } else {
+                Box::new(next.into_iter())
+            };
+            for next in next {
+                if !self.seen[next] {
+                    self.seen[next] = !self.depth_first;
+                    self.to_visit.push_back(next);
+                }
+            }
+            return Some(id);
+        }
+    }
+}
+
+// a set of edges whose removal splits an undirected graph in two
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut {
+    pub edges: Vec<(usize, usize)>,
+    // true for the nodes on the same side as node 0
+    pub side: Vec<bool>,
+}
+
+impl Cut {
+    pub fn sizes(&self) -> (usize, usize) {
+        let first = self.side.iter().filter(|&&s| s).count();
+        (first, self.side.len() - first)
+    }
+}
+
+// Global minimum edge cut of an undirected graph given as adjacency lists of node indices
+// (every edge listed from both ends). Node 0 is on one side of any cut, so the best cut is
+// the smallest of the 0-t cuts over all other nodes t, each one found by a unit capacity
+// max-flow that gives up as soon as it can't beat the best cut so far.
+pub fn min_cut(adjacency: &[Vec<usize>]) -> Option<Cut> {
+    let reverse = reverse_arcs(adjacency);
+    let mut best: Option<Cut> = None;
+    for target in 1..adjacency.len() {
+        let limit = best.as_ref().map_or(usize::MAX, |cut| cut.edges.len());
+        if let Some(cut) = limited_cut(adjacency, &reverse, 0, target, limit) {
+            best = Some(cut);
+        }
+    }
+    best
+}
+
+// the minimum source-target cut if it has fewer than `limit` edges; `reverse` comes from
+// reverse_arcs
+fn limited_cut(
+    adjacency: &[Vec<usize>],
+    reverse: &[Vec<usize>],
+    source: usize,
+    target: usize,
+    limit: usize,
+) -> Option<Cut> {
+    // net flow on every arc, so an undirected edge carries at most 1 either way
+    let mut flow: Vec<Vec<i8>> = adjacency.iter().map(|next| vec![0; next.len()]).collect();
+    let mut total = 0;
+    loop {
+        let parents = residual_bfs(adjacency, &flow, source);
+        if parents[target].is_none() {
+            break;
+        }
+        total += 1;
+        if total >= limit {
+            return None;
+        }
+        let mut node = target;
+        while node != source {
+            let (prev, i) = parents[node].unwrap();
+            flow[prev][i] += 1;
+            flow[node][reverse[prev][i]] -= 1;
+            node = prev;
+        }
+    }
+
+    // what is still reachable from the source once the flow saturates is its side of the cut
+    let parents = residual_bfs(adjacency, &flow, source);
+    let side: Vec<bool> = (0..adjacency.len())
+        .map(|node| node == source || parents[node].is_some())
+        .collect();
+    let edges = adjacency
+        .iter()
+        .enumerate()
+        .filter(|&(node, _)| side[node])
+        .flat_map(|(node, next)| next.iter().filter(|&&n| !side[n]).map(move |&n| (node, n)))
+        .collect();
+    Some(Cut { edges, side })
+}
+
+// index of the opposite arc of every arc, the k-th a-b arc pairs with the k-th b-a arc
+fn reverse_arcs(adjacency: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let mut reverse: Vec<Vec<usize>> = adjacency.iter().map(|next| vec![0; next.len()]).collect();
+    for (node, next) in adjacency.iter().enumerate() {
+        for (i, &other) in next.iter().enumerate() {
+            let k = next[..i].iter().filter(|&&n| n == other).count();
+            reverse[node][i] = adjacency[other]
+                .iter()
+                .enumerate()
+                .filter(|&(_, &n)| n == node)
+                .nth(k)
+                .expect("Every edge must be listed from both ends")
+                .0;
+        }
+    }
+    reverse
+}
+
+// for every node reached through arcs with spare capacity, the node and arc it came from
+fn residual_bfs(
+    adjacency: &[Vec<usize>],
+    flow: &[Vec<i8>],
+    source: usize,
+) -> Vec<Option<(usize, usize)>> {
+    let mut parents = vec![None; adjacency.len()];
+    let mut to_visit = VecDeque::from([source]);
+    while let Some(node) = to_visit.pop_front() {
+        for (i, &next) in adjacency[node].iter().enumerate() {
+            if next != source && parents[next].is_none() && flow[node][i] < 1 {
+                parents[next] = Some((node, i));
+                to_visit.push_back(next);
+            }
+        }
+    }
+    parents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn undirected(n: usize, edges: &[(usize, usize)]) -> Vec<Vec<usize>> {
+        let mut adjacency = vec![vec![]; n];
+        for &(a, b) in edges {
+            adjacency[a].push(b);
+            adjacency[b].push(a);
+        }
+        adjacency
+    }
+
+    #[test]
+    fn interned_directed_graph() {
+        let mut graph = Graph::directed();
+        for (from, to, weight) in [("a", "b", 1), ("a", "c", 2), ("c", "b", 3), ("d", "e", 4)] {
+            graph.add_edge(from, to, weight);
+        }
+        let [a, b, c, d, e] = ["a", "b", "c", "d", "e"].map(|name| graph.get_id(name).unwrap());
+        assert_eq!((a, b, c, d, e), (0, 1, 2, 3, 4));
+        assert_eq!(graph.name(c), "c");
+        assert_eq!(graph.get_id("x"), None);
+        assert_eq!(graph.edges(a), &[(b, 1), (c, 2)]);
+        assert_eq!(graph.predecessors(b).collect::<Vec<_>>(), vec![a, c]);
+        assert_eq!((graph.out_degree(b), graph.in_degree(b)), (0, 2));
+        assert_eq!(graph.bfs(a).collect::<Vec<_>>(), vec![a, b, c]);
+        assert_eq!(graph.dfs(a).collect::<Vec<_>>(), vec![a, b, c]);
+        assert_eq!(graph.bfs(c).collect::<Vec<_>>(), vec![c, b]);
+        assert_eq!(graph.components(), vec![vec![a, c, b], vec![d, e]]);
+    }
+
+    #[test]
+    fn traversal_orders() {
+        let mut graph = Graph::undirected();
+        for (from, to) in [("r", "a"), ("r", "b"), ("a", "c"), ("b", "d")] {
+            graph.add_edge(from, to, ());
+        }
+        let names = |ids: Vec<usize>| ids.into_iter().map(|id| graph.name(id)).collect::<String>();
+        assert_eq!(names(graph.bfs(0).collect()), "rabcd");
+        assert_eq!(names(graph.dfs(0).collect()), "racbd");
+        assert_eq!(graph.in_degree(0), 2);
+        assert_eq!(graph.components().len(), 1);
+    }
+
+    #[test]
+    fn bridge_between_two_squares() {
+        // two 4-cycles with diagonals, joined by the single edge 2-5
+        let adjacency = undirected(
+            8,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (3, 0),
+                (0, 2),
+                (1, 3),
+                (4, 5),
+                (5, 6),
+                (6, 7),
+                (7, 4),
+                (4, 6),
+                (5, 7),
+                (2, 5),
+            ],
+        );
+        let cut = min_cut(&adjacency).unwrap();
+        assert_eq!(cut.edges, vec![(2, 5)]);
+        assert_eq!(cut.sizes(), (4, 4));
+        assert_eq!(
+            cut.side,
+            vec![true, true, true, true, false, false, false, false]
+        );
+    }
+
+    #[test]
+    fn disconnected_graph() {
+        let adjacency = undirected(4, &[(0, 1), (2, 3)]);
+        let cut = min_cut(&adjacency).unwrap();
+        assert!(cut.edges.is_empty());
+        assert_eq!(cut.sizes(), (2, 2));
+        assert_eq!(min_cut(&[vec![]]), None);
+    }
+
+    #[test]
+    fn three_wires_between_two_cliques() {
+        let mut edges = vec![(0, 5), (1, 6), (2, 7)];
+        for clique in [0, 5] {
+            for a in clique..clique + 5 {
+                for b in a + 1..clique + 5 {
+                    edges.push((a, b));
+                }
+            }
+        }
+        // and a doubled wire inside a clique, which must not be mistaken for a cut
+        edges.push((0, 1));
+        let adjacency = undirected(10, &edges);
+        let cut = min_cut(&adjacency).unwrap();
+        assert_eq!(cut.edges, vec![(0, 5), (1, 6), (2, 7)]);
+        assert_eq!(cut.sizes(), (5, 5));
+    }
+}
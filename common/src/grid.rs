@@ -0,0 +1,783 @@
+use std::{
+    collections::{hash_map::Entry, HashMap, VecDeque},
+    fmt,
+    ops::{Add, AddAssign, Index, IndexMut, Sub, SubAssign},
+};
+
+use num::{Num, Signed};
+
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
+pub struct Point2D<T>
+where
+    T: Num,
+{
+    pub x: T,
+    pub y: T,
+}
+
+pub type Path<T> = Vec<Point2D<T>>;
+
+impl<T> Point2D<T>
+where
+    T: Num + Copy + Eq + Ord,
+{
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn in_positive_range(&self, x_max_exclusive: T, y_max_exclusive: T) -> bool {
+        return self.x >= T::zero()
+            && self.x < x_max_exclusive
+            && self.y >= T::zero()
+            && self.y < y_max_exclusive;
+    }
+
+    pub fn move_dxy(&self, dx: T, dy: T) -> Self {
+        return Self::new(self.x + dx, self.y + dy);
+    }
+
+    pub fn move_dir(&self, dir: Direction, steps: T) -> Point2D<T> {
+        match dir {
+            Direction::North => Point2D::new(self.x, self.y - steps),
+            Direction::East => Point2D::new(self.x + steps, self.y),
+            Direction::South => Point2D::new(self.x, self.y + steps),
+            Direction::West => Point2D::new(self.x - steps, self.y),
+        }
+    }
+
+    // inside the box from min to max, borders included
+    pub fn within(&self, min: &Point2D<T>, max: &Point2D<T>) -> bool {
+        (min.x..=max.x).contains(&self.x) && (min.y..=max.y).contains(&self.y)
+    }
+}
+impl<T> Point2D<T>
+where
+    T: Signed + Copy + Eq + Ord,
+{
+    pub fn manhattan_distance(&self, destination: &Point2D<T>) -> T {
+        return (self.x - destination.x).abs() + (self.y - destination.y).abs();
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Point3D<T: Num> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3D<T>
+where
+    T: Num,
+{
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> Point3D<T>
+where
+    T: Num + Copy,
+{
+    // the point seen from above, dropping z
+    pub fn xy(&self) -> Point2D<T> {
+        Point2D {
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Cube<T: Num> {
+    pub low_corner: Point3D<T>,
+    pub high_corner: Point3D<T>,
+}
+
+impl<T> Cube<T>
+where
+    T: Num
+        + Eq
+        + Ord
+        + Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + AddAssign<T>
+        + SubAssign<T>
+        + From<i8>,
+{
+    pub fn new(low_corner: Point3D<T>, high_corner: Point3D<T>) -> Self {
+        let min_x = *std::cmp::min(&low_corner.x, &high_corner.x);
+        let min_y = *std::cmp::min(&low_corner.y, &high_corner.y);
+        let min_z = *std::cmp::min(&low_corner.z, &high_corner.z);
+        let max_x = *std::cmp::max(&low_corner.x, &high_corner.x);
+        let max_y = *std::cmp::max(&low_corner.y, &high_corner.y);
+        let max_z = *std::cmp::max(&low_corner.z, &high_corner.z);
+        Self {
+            low_corner: Point3D::new(min_x, min_y, min_z),
+            high_corner: Point3D::new(max_x, max_y, max_z),
+        }
+    }
+
+    pub fn contains(&self, coord: &Point3D<T>) -> bool {
+        coord.x >= self.low_corner.x
+            && coord.x <= self.high_corner.x
+            && coord.y >= self.low_corner.y
+            && coord.y <= self.high_corner.y
+            && coord.z >= self.low_corner.z
+            && coord.z <= self.high_corner.z
+    }
+
+    pub fn crosses(&self, other: &Cube<T>) -> bool {
+        self.low_corner.x <= other.high_corner.x
+            && self.high_corner.x >= other.low_corner.x
+            && self.low_corner.y <= other.high_corner.y
+            && self.high_corner.y >= other.low_corner.y
+            && self.low_corner.z <= other.high_corner.z
+            && self.high_corner.z >= other.low_corner.z
+    }
+
+    pub fn move_down(&mut self) {
+        self.low_corner.z -= T::from(1);
+        self.high_corner.z -= T::from(1);
+    }
+
+    pub fn move_up(&mut self) {
+        self.low_corner.z += T::from(1);
+        self.high_corner.z += T::from(1);
+    }
+}
+
+#[repr(u8)]
+#[derive(Eq, Debug, Copy, Clone, PartialEq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub fn from_char(c: char) -> Self {
+        match c {
+            'N' | 'U' => Direction::North,
+            'E' | 'R' => Direction::East,
+            'S' | 'D' => Direction::South,
+            'W' | 'L' => Direction::West,
+            _ => panic!("Invalid direction char"),
+        }
+    }
+
+    pub fn turn_cw(&self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn turn_ccw(&self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::West => Direction::South,
+            Direction::South => Direction::East,
+            Direction::East => Direction::North,
+        }
+    }
+
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::West => Direction::East,
+            Direction::South => Direction::North,
+            Direction::East => Direction::West,
+        }
+    }
+}
+
+pub fn transpose<T: Clone>(array2d: &Vec<Vec<T>>) -> Vec<Vec<T>> {
+    let mut result = Vec::<Vec<T>>::new();
+    for x in 0..array2d[0].len() {
+        let mut row = Vec::<T>::new();
+        for y in 0..array2d.len() {
+            row.push(array2d[y][x].clone());
+        }
+        result.push(row);
+    }
+    result
+}
+
+pub struct MapHashMap<K, V>
+where
+    K: Num,
+{
+    pub map: HashMap<Point2D<K>, V>,
+}
+
+impl<K: Num, V> Default for MapHashMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Num, V> MapHashMap<K, V> {
+    pub fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Num, V> Index<Point2D<K>> for MapHashMap<K, V>
+where
+    K: Eq + std::hash::Hash,
+{
+    type Output = V;
+
+    fn index(&self, index: Point2D<K>) -> &Self::Output {
+        self.map.get(&index).unwrap()
+    }
+}
+
+impl<K: Num, V> Index<&Point2D<K>> for MapHashMap<K, V>
+where
+    K: Eq + std::hash::Hash,
+{
+    type Output = V;
+
+    fn index(&self, index: &Point2D<K>) -> &Self::Output {
+        self.map.get(index).unwrap()
+    }
+}
+
+impl MapHashMap<i32, char> {
+    pub fn parse_map(lines: &Vec<String>) -> Self {
+        let mut map = Self::new();
+        lines.iter().enumerate().for_each(|(y, line)| {
+            line.chars().enumerate().for_each(|(x, c)| {
+                map.map.insert(Point2D::new(x as i32, y as i32), c);
+            });
+        });
+        map
+    }
+
+    pub fn find(&self, c: char) -> Vec<&Point2D<i32>> {
+        self.map
+            .iter()
+            .filter(|(_, &v)| v == c)
+            .map(|(k, _)| k)
+            .collect::<Vec<&Point2D<i32>>>()
+    }
+}
+
+// how positions outside of the stored grid are addressed
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Edges {
+    // there's nothing outside
+    Bounded,
+    // leaving on one side enters on the opposite one, positions stay inside the grid
+    Wrapping,
+    // the grid repeats forever and positions keep their real coordinates
+    Tiled,
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct MapVector<V> {
+    pub map: Vec<Vec<V>>,
+    pub edges: Edges,
+}
+
+impl<V> MapVector<V> {
+    pub fn empty(size: Point2D<usize>, value: V) -> Self
+    where
+        V: Clone,
+    {
+        let map = vec![vec![value.clone(); size.x]; size.y];
+        Self {
+            map,
+            edges: Edges::Bounded,
+        }
+    }
+
+    pub fn new<F>(lines: &Vec<String>, convert: F) -> Self
+    where
+        F: Fn(char) -> V,
+    {
+        let map = lines
+            .iter()
+            .map(|line| line.chars().map(&convert).collect::<Vec<V>>())
+            .collect::<Vec<Vec<V>>>();
+        Self {
+            map,
+            edges: Edges::Bounded,
+        }
+    }
+
+    pub fn with_edges(mut self, edges: Edges) -> Self {
+        self.edges = edges;
+        self
+    }
+
+    // where a step onto `position` lands, None if it falls off a bounded map
+    pub fn resolve(&self, position: Point2D<isize>) -> Option<Point2D<isize>> {
+        match self.edges {
+            Edges::Bounded => self.is_in_map(position).then_some(position),
+            Edges::Wrapping => Some(self.wrap(position)),
+            Edges::Tiled => Some(position),
+        }
+    }
+
+    fn wrap(&self, position: Point2D<isize>) -> Point2D<isize> {
+        let size = self.get_size();
+        Point2D::new(
+            position.x.rem_euclid(size.x as isize),
+            position.y.rem_euclid(size.y as isize),
+        )
+    }
+
+    // row and column in the stored grid holding the value of `position`
+    fn cell(&self, position: Point2D<isize>) -> Option<(usize, usize)> {
+        let position = match self.edges {
+            Edges::Bounded => position,
+            Edges::Wrapping | Edges::Tiled => self.wrap(position),
+        };
+        let y = usize::try_from(position.y).ok()?;
+        let x = usize::try_from(position.x).ok()?;
+        (y < self.map.len() && x < self.map[y].len()).then_some((y, x))
+    }
+
+    pub fn get(&self, position: Point2D<isize>) -> Option<&V> {
+        let (y, x) = self.cell(position)?;
+        Some(&self.map[y][x])
+    }
+
+    pub fn get_mut(&mut self, position: Point2D<isize>) -> Option<&mut V> {
+        let (y, x) = self.cell(position)?;
+        Some(&mut self.map[y][x])
+    }
+
+    fn outside<T: fmt::Debug>(&self, position: T) -> ! {
+        let size = self.get_size();
+        panic!("{position:?} is outside of the {}x{} map", size.x, size.y)
+    }
+
+    pub fn is_in_map(&self, position: Point2D<isize>) -> bool {
+        return position.x >= 0
+            && position.y >= 0
+            && position.y < self.map.len() as isize
+            && position.x < self.map[0].len() as isize;
+    }
+
+    pub fn find(&self, what: V) -> Vec<Point2D<isize>>
+    where
+        V: PartialEq + Copy,
+    {
+        let mut res = vec![];
+        for y in 0..self.map.len() {
+            for x in 0..self.map[y].len() {
+                if self.map[y][x] == what {
+                    res.push(Point2D::new(x as isize, y as isize));
+                }
+            }
+        }
+        res
+    }
+
+    pub fn find_in_front(
+        &self,
+        position: Point2D<isize>,
+        direction: Direction,
+        what: V,
+    ) -> Option<Point2D<isize>>
+    where
+        V: PartialEq + Copy,
+    {
+        let mut current = position;
+        loop {
+            current = current.move_dir(direction, 1);
+            if !current.in_positive_range(self.map[0].len() as isize, self.map.len() as isize) {
+                return None;
+            }
+            if self[current] == what {
+                return Some(current);
+            }
+        }
+    }
+
+    pub fn get_neighbors_pos<F>(&self, pos: &Point2D<isize>, mut filter: F) -> Vec<Point2D<isize>>
+    where
+        F: FnMut(&Point2D<isize>) -> bool,
+    {
+        let mut res = vec![];
+        for direction in [
+            Direction::North,
+            Direction::East,
+            Direction::South,
+            Direction::West,
+        ] {
+            if let Some(new_pos) = self.resolve(pos.move_dir(direction, 1)) {
+                if filter(&new_pos) {
+                    res.push(new_pos);
+                }
+            }
+        }
+        res
+    }
+
+    pub fn get_neighbors<F>(&self, pos: &Point2D<isize>, filter: F) -> Vec<V>
+    where
+        F: FnMut(&Point2D<isize>) -> bool,
+        V: Copy,
+    {
+        self.get_neighbors_pos(pos, filter)
+            .iter()
+            .map(|&p| self[p])
+            .collect()
+    }
+
+    pub fn get_size(&self) -> Point2D<usize> {
+        Point2D::new(self.map[0].len(), self.map.len())
+    }
+
+    // breadth-first distance of every cell from the nearest start, moving only onto cells
+    // accepted by `passable` (the starts themselves always count); None if unreachable
+    pub fn bfs<F>(
+        &self,
+        starts: impl IntoIterator<Item = Point2D<isize>>,
+        mut passable: F,
+    ) -> MapVector<Option<u32>>
+    where
+        F: FnMut(&V) -> bool,
+    {
+        assert!(
+            self.edges != Edges::Tiled,
+            "A tiled map has no end, use bfs_within"
+        );
+        // with the same edges, so paths across the seam of a wrapping map can be walked back
+        let mut distances = MapVector::empty(self.get_size(), None).with_edges(self.edges);
+        let mut to_visit = VecDeque::new();
+        for start in starts {
+            if self.is_in_map(start) && distances[start].is_none() {
+                distances[start] = Some(0);
+                to_visit.push_back(start);
+            }
+        }
+        while let Some(pos) = to_visit.pop_front() {
+            let next_distance = distances[pos].map(|d| d + 1);
+            for next in
+                self.get_neighbors_pos(&pos, |&n| distances[n].is_none() && passable(&self[n]))
+            {
+                distances[next] = next_distance;
+                to_visit.push_back(next);
+            }
+        }
+        distances
+    }
+
+    // labels the 4-connected regions, two neighbours being joined when `connected` holds for
+    // their values; returns the label of every cell and the number of regions
+    pub fn components<F>(&self, mut connected: F) -> (MapVector<usize>, usize)
+    where
+        F: FnMut(&V, &V) -> bool,
+    {
+        assert!(self.edges != Edges::Tiled, "A tiled map has no end");
+        let mut labels = MapVector::empty(self.get_size(), usize::MAX);
+        let mut count = 0;
+        for y in 0..self.map.len() as isize {
+            for x in 0..self.map[0].len() as isize {
+                let start = Point2D::new(x, y);
+                if labels[start] != usize::MAX {
+                    continue;
+                }
+                labels[start] = count;
+                let mut to_visit = vec![start];
+                while let Some(pos) = to_visit.pop() {
+                    for next in self.get_neighbors_pos(&pos, |&n| {
+                        labels[n] == usize::MAX && connected(&self[pos], &self[n])
+                    }) {
+                        labels[next] = count;
+                        to_visit.push(next);
+                    }
+                }
+                count += 1;
+            }
+        }
+        (labels, count)
+    }
+
+    // like bfs, but only up to `max_distance` steps; works on every kind of edges, and
+    // positions on a tiled map keep their coordinates outside of the stored grid
+    pub fn bfs_within<F>(
+        &self,
+        starts: impl IntoIterator<Item = Point2D<isize>>,
+        mut passable: F,
+        max_distance: u32,
+    ) -> HashMap<Point2D<isize>, u32>
+    where
+        F: FnMut(&V) -> bool,
+    {
+        let mut distances = HashMap::new();
+        let mut to_visit = VecDeque::new();
+        for start in starts.into_iter().filter_map(|start| self.resolve(start)) {
+            if let Entry::Vacant(entry) = distances.entry(start) {
+                entry.insert(0);
+                to_visit.push_back((start, 0));
+            }
+        }
+        while let Some((pos, distance)) = to_visit.pop_front() {
+            if distance == max_distance {
+                continue;
+            }
+            for next in
+                self.get_neighbors_pos(&pos, |n| !distances.contains_key(n) && passable(&self[n]))
+            {
+                distances.insert(next, distance + 1);
+                to_visit.push_back((next, distance + 1));
+            }
+        }
+        distances
+    }
+}
+
+impl MapVector<Option<u32>> {
+    // walks a distance map from `bfs` back down to a start, None if `end` wasn't reached
+    pub fn path_to(&self, end: Point2D<isize>) -> Option<Path<isize>> {
+        let mut path = vec![end];
+        let mut distance = (*self.map.get(end.y as usize)?.get(end.x as usize)?)?;
+        while distance > 0 {
+            let pos = *path.last().unwrap();
+            let prev = self
+                .get_neighbors_pos(&pos, |&n| self[n] == Some(distance - 1))
+                .first()
+                .copied()?;
+            path.push(prev);
+            distance -= 1;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+impl<V> fmt::Debug for MapVector<V>
+where
+    V: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.map.len() {
+            for x in 0..self.map[y].len() {
+                write!(f, "{}", self.map[y][x])?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// indexing follows the map's edges and panics on positions that aren't on it
+impl<V> Index<Point2D<usize>> for MapVector<V> {
+    type Output = V;
+
+    fn index(&self, index: Point2D<usize>) -> &Self::Output {
+        &self[&index]
+    }
+}
+
+impl<V> Index<&Point2D<usize>> for MapVector<V> {
+    type Output = V;
+
+    fn index(&self, index: &Point2D<usize>) -> &Self::Output {
+        match (isize::try_from(index.x), isize::try_from(index.y)) {
+            (Ok(x), Ok(y)) => &self[Point2D::new(x, y)],
+            _ => self.outside(index),
+        }
+    }
+}
+
+impl<V> IndexMut<Point2D<usize>> for MapVector<V> {
+    fn index_mut(&mut self, index: Point2D<usize>) -> &mut Self::Output {
+        &mut self[&index]
+    }
+}
+
+impl<V> IndexMut<&Point2D<usize>> for MapVector<V> {
+    fn index_mut(&mut self, index: &Point2D<usize>) -> &mut Self::Output {
+        match (isize::try_from(index.x), isize::try_from(index.y)) {
+            (Ok(x), Ok(y)) => &mut self[Point2D::new(x, y)],
+            _ => self.outside(index),
+        }
+    }
+}
+
+impl<V> Index<Point2D<isize>> for MapVector<V> {
+    type Output = V;
+
+    fn index(&self, index: Point2D<isize>) -> &Self::Output {
+        self.get(index).unwrap_or_else(|| self.outside(index))
+    }
+}
+
+impl<V> IndexMut<Point2D<isize>> for MapVector<V> {
+    fn index_mut(&mut self, index: Point2D<isize>) -> &mut Self::Output {
+        match self.cell(index) {
+            Some((y, x)) => &mut self.map[y][x],
+            None => self.outside(index),
+        }
+    }
+}
+
+impl<V> Index<&Point2D<isize>> for MapVector<V> {
+    type Output = V;
+
+    fn index(&self, index: &Point2D<isize>) -> &Self::Output {
+        &self[*index]
+    }
+}
+
+impl<V> IndexMut<&Point2D<isize>> for MapVector<V> {
+    fn index_mut(&mut self, index: &Point2D<isize>) -> &mut Self::Output {
+        &mut self[*index]
+    }
+}
+
+#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+pub enum EdgeType {
+    Horizontal,
+    Vertical,
+    ULC,
+    URC,
+    DLC,
+    DRC,
+}
+
+impl EdgeType {
+    pub fn is_corner(&self) -> bool {
+        matches!(
+            self,
+            EdgeType::ULC | EdgeType::URC | EdgeType::DLC | EdgeType::DRC
+        )
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, EdgeType::Horizontal)
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, EdgeType::Vertical)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(rows: &str) -> MapVector<char> {
+        let lines: Vec<String> = rows.lines().map(String::from).collect();
+        MapVector::new(&lines, |c| c)
+    }
+
+    #[test]
+    fn bfs_distances_and_path() {
+        let maze = map("S.#.\n.##.\n....\n#.#E");
+        let distances = maze.bfs([Point2D::new(0, 0)], |&c| c != '#');
+        assert_eq!(distances[Point2D::new(3isize, 3)], Some(6));
+        assert_eq!(distances[Point2D::new(3isize, 0)], Some(7));
+        assert_eq!(distances[Point2D::new(2isize, 0)], None);
+        let path = distances.path_to(Point2D::new(3, 3)).unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!(path[0], Point2D::new(0, 0));
+        assert!(path.windows(2).all(|w| w[0].manhattan_distance(&w[1]) == 1));
+        assert_eq!(distances.path_to(Point2D::new(2, 0)), None);
+        assert_eq!(distances.path_to(Point2D::new(9, 9)), None);
+
+        // from both ends at once every cell is at most 4 steps away
+        let both = maze.bfs([Point2D::new(0, 0), Point2D::new(3, 3)], |&c| c != '#');
+        assert_eq!(both.map.iter().flatten().flatten().max(), Some(&4));
+    }
+
+    #[test]
+    fn edges() {
+        let bounded = map("ab\ncd");
+        assert_eq!(bounded.get(Point2D::new(1, 1)), Some(&'d'));
+        assert_eq!(bounded.get(Point2D::new(2, 0)), None);
+        assert_eq!(bounded.get(Point2D::new(0, -1)), None);
+        assert_eq!(
+            bounded
+                .get_neighbors_pos(&Point2D::new(0, 0), |_| true)
+                .len(),
+            2
+        );
+
+        let wrapping = map("ab\ncd").with_edges(Edges::Wrapping);
+        assert_eq!(wrapping[Point2D::new(-1isize, 2)], 'b');
+        assert_eq!(
+            wrapping.resolve(Point2D::new(2, -1)),
+            Some(Point2D::new(0, 1))
+        );
+        assert_eq!(
+            wrapping
+                .get_neighbors_pos(&Point2D::new(0, 0), |_| true)
+                .len(),
+            4
+        );
+
+        let tiled = map("ab\ncd").with_edges(Edges::Tiled);
+        assert_eq!(tiled[Point2D::new(5isize, 5)], 'd');
+        assert!(tiled
+            .get_neighbors_pos(&Point2D::new(0, 0), |_| true)
+            .contains(&Point2D::new(-1, 0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "is outside of the 2x2 map")]
+    fn index_out_of_bounds() {
+        let _ = map("ab\ncd")[Point2D::new(0isize, 2)];
+    }
+
+    #[test]
+    fn bfs_on_a_tiled_map() {
+        // the wall only blocks inside a tile, around it the plane stays open
+        let garden = map("...\n.#.\n...").with_edges(Edges::Tiled);
+        let distances = garden.bfs_within([Point2D::new(0, 0)], |&c| c != '#', 4);
+        assert_eq!(distances[&Point2D::new(-4, 0)], 4);
+        assert_eq!(distances[&Point2D::new(2, 2)], 4);
+        assert!(!distances.contains_key(&Point2D::new(1, 1)));
+        assert!(!distances.contains_key(&Point2D::new(5, 0)));
+        let wrapping = garden.clone().with_edges(Edges::Wrapping);
+        assert_eq!(
+            wrapping.bfs([Point2D::new(0, 0)], |&c| c != '#')[Point2D::new(2isize, 2)],
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn path_across_a_wrapping_edge() {
+        let torus = map("...\n.#.\n...").with_edges(Edges::Wrapping);
+        let distances = torus.bfs([Point2D::new(0, 0)], |&c| c != '#');
+        let path = distances.path_to(Point2D::new(2, 2)).unwrap();
+        assert_eq!(path.len(), 3);
+        assert_eq!(path[0], Point2D::new(0, 0));
+        assert!(path
+            .windows(2)
+            .all(|w| torus.get_neighbors_pos(&w[0], |_| true).contains(&w[1])));
+    }
+
+    #[test]
+    fn components_of_equal_cells() {
+        let garden = map("AAB\nBAB\nBBA");
+        let (labels, count) = garden.components(|a, b| a == b);
+        assert_eq!(count, 4);
+        assert_eq!(
+            labels[Point2D::new(1isize, 1)],
+            labels[Point2D::new(0isize, 0)]
+        );
+        assert_eq!(
+            labels[Point2D::new(2isize, 0)],
+            labels[Point2D::new(2isize, 1)]
+        );
+        assert_ne!(
+            labels[Point2D::new(2isize, 1)],
+            labels[Point2D::new(1isize, 2)]
+        );
+    }
+}
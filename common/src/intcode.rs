@@ -0,0 +1,300 @@
+use std::collections::{HashMap, VecDeque};
+use std::thread;
+
+use crossbeam_channel::{unbounded, Receiver, Sender};
+
+use crate::{AocError, Result};
+
+// why `run` gave back control
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Output(i64),
+    // the input queue is empty, `run` again once something was pushed
+    NeedsInput,
+    Halted,
+}
+
+// the 2019 Intcode computer; memory is sparse, any address can be read or written and
+// holds 0 until then
+#[derive(Debug, Clone)]
+pub struct Machine {
+    memory: HashMap<i64, i64>,
+    ip: i64,
+    relative_base: i64,
+    inputs: VecDeque<i64>,
+}
+
+impl Machine {
+    pub fn new(program: &[i64]) -> Self {
+        Self {
+            memory: (0..).zip(program.iter().copied()).collect(),
+            ip: 0,
+            relative_base: 0,
+            inputs: VecDeque::new(),
+        }
+    }
+
+    pub fn parse(program: &str) -> Result<Self> {
+        let program = program
+            .trim()
+            .split(',')
+            .map(|v| v.trim().parse())
+            .collect::<std::result::Result<Vec<i64>, _>>()?;
+        Ok(Self::new(&program))
+    }
+
+    pub fn read(&self, address: i64) -> i64 {
+        self.memory.get(&address).copied().unwrap_or(0)
+    }
+
+    pub fn write(&mut self, address: i64, value: i64) {
+        self.memory.insert(address, value);
+    }
+
+    pub fn push_input(&mut self, value: i64) {
+        self.inputs.push_back(value);
+    }
+
+    // runs until the next output, until an input is needed but there is none, or to the end
+    pub fn run(&mut self) -> Result<State> {
+        loop {
+            let instruction = self.read(self.ip);
+            let (a, b, c) = (self.param(1)?, self.param(2)?, self.param(3)?);
+            match instruction % 100 {
+                1 => self.binary(a, b, c, |x, y| x + y)?,
+                2 => self.binary(a, b, c, |x, y| x * y)?,
+                3 => {
+                    let Some(value) = self.inputs.pop_front() else {
+                        return Ok(State::NeedsInput);
+                    };
+                    self.store(a, value)?;
+                    self.ip += 2;
+                }
+                4 => {
+                    self.ip += 2;
+                    return Ok(State::Output(self.load(a)));
+                }
+                5 | 6 => {
+                    let jump = (self.load(a) != 0) == (instruction % 100 == 5);
+                    self.ip = if jump { self.load(b) } else { self.ip + 3 };
+                }
+                7 => self.binary(a, b, c, |x, y| (x < y) as i64)?,
+                8 => self.binary(a, b, c, |x, y| (x == y) as i64)?,
+                9 => {
+                    self.relative_base += self.load(a);
+                    self.ip += 2;
+                }
+                99 => return Ok(State::Halted),
+                _ => {
+                    return Err(AocError::Parse(format!(
+                        "Unknown intcode instruction {instruction} at {}",
+                        self.ip
+                    )))
+                }
+            }
+        }
+    }
+
+    // every output until the machine halts, with all of its inputs given upfront
+    pub fn run_to_end(&mut self, inputs: &[i64]) -> Result<Vec<i64>> {
+        self.inputs.extend(inputs);
+        let mut outputs = vec![];
+        loop {
+            match self.run()? {
+                State::Output(value) => outputs.push(value),
+                State::NeedsInput => {
+                    return Err(AocError::Parse(
+                        "Intcode machine ran out of input".to_string(),
+                    ))
+                }
+                State::Halted => return Ok(outputs),
+            }
+        }
+    }
+
+    // runs to the end on a thread of its own, waiting on `input` whenever the queue is empty;
+    // outputs nobody listens to anymore are dropped
+    pub fn run_connected(&mut self, input: &Receiver<i64>, output: &Sender<i64>) -> Result<()> {
+        loop {
+            match self.run()? {
+                State::Output(value) => {
+                    let _ = output.send(value);
+                }
+                State::NeedsInput => match input.recv() {
+                    Ok(value) => self.push_input(value),
+                    Err(_) => {
+                        return Err(AocError::Parse(
+                            "Intcode input closed while waiting on it".to_string(),
+                        ))
+                    }
+                },
+                State::Halted => return Ok(()),
+            }
+        }
+    }
+
+    // the address or the value of the n-th parameter of the current instruction
+    fn param(&self, n: u32) -> Result<Param> {
+        let raw = self.read(self.ip + n as i64);
+        match self.read(self.ip) / 10i64.pow(n + 1) % 10 {
+            0 => Ok(Param::Address(raw)),
+            1 => Ok(Param::Value(raw)),
+            2 => Ok(Param::Address(self.relative_base + raw)),
+            mode => Err(AocError::Parse(format!(
+                "Unknown intcode parameter mode {mode} at {}",
+                self.ip
+            ))),
+        }
+    }
+
+    fn load(&self, param: Param) -> i64 {
+        match param {
+            Param::Address(address) => self.read(address),
+            Param::Value(value) => value,
+        }
+    }
+
+    fn store(&mut self, param: Param, value: i64) -> Result<()> {
+        match param {
+            Param::Address(address) => {
+                self.write(address, value);
+                Ok(())
+            }
+            Param::Value(_) => Err(AocError::Parse(format!(
+                "Intcode write in immediate mode at {}",
+                self.ip
+            ))),
+        }
+    }
+
+    fn binary(&mut self, a: Param, b: Param, c: Param, op: impl Fn(i64, i64) -> i64) -> Result<()> {
+        let value = op(self.load(a), self.load(b));
+        self.store(c, value)?;
+        self.ip += 4;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Param {
+    Address(i64),
+    Value(i64),
+}
+
+// one copy of `machine` per setting, each told its setting first, the outputs of each going
+// to the next one and `input` to the first; with `feedback` the last one's outputs also go
+// back to the first, like the amplifiers of 2019 day 7; returns the last output of the last
+pub fn chain(machine: &Machine, settings: &[i64], input: i64, feedback: bool) -> Result<i64> {
+    let channels: Vec<(Sender<i64>, Receiver<i64>)> =
+        (0..=settings.len()).map(|_| unbounded()).collect();
+    for (setting, (tx, _)) in settings.iter().zip(&channels) {
+        tx.send(*setting).unwrap();
+    }
+    let (first, last_output) = (channels[0].0.clone(), channels[settings.len()].1.clone());
+    first.send(input).unwrap();
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..settings.len())
+            .map(|i| {
+                let rx = channels[i].1.clone();
+                let tx = channels[i + 1].0.clone();
+                let mut machine = machine.clone();
+                scope.spawn(move || machine.run_connected(&rx, &tx))
+            })
+            .collect();
+        // only the threads hold the channels from now on, so they close as machines stop
+        drop(channels);
+
+        // without feedback nothing more goes to the first one, which must see its input close
+        // if it asks for more
+        let first = feedback.then_some(first);
+        let mut last = None;
+        for value in last_output {
+            last = Some(value);
+            if let Some(first) = &first {
+                let _ = first.send(value);
+            }
+        }
+        drop(first);
+        for handle in handles {
+            handle.join().unwrap()?;
+        }
+        last.ok_or_else(|| AocError::Parse("The last machine had no output".to_string()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outputs(program: &str, inputs: &[i64]) -> Vec<i64> {
+        Machine::parse(program).unwrap().run_to_end(inputs).unwrap()
+    }
+
+    #[test]
+    fn modes_and_jumps() {
+        // 2019 day 2: 1,0,0,0 adds the first address to itself
+        let mut machine = Machine::parse("1,9,10,3,2,3,11,0,99,30,40,50").unwrap();
+        assert_eq!(machine.run().unwrap(), State::Halted);
+        assert_eq!(machine.read(0), 3500);
+        // 2019 day 5: is the input equal to 8, in position and immediate mode
+        assert_eq!(outputs("3,9,8,9,10,9,4,9,99,-1,8", &[8]), vec![1]);
+        assert_eq!(outputs("3,3,1107,-1,8,3,4,3,99", &[9]), vec![0]);
+        let compare = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,\
+                       1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,\
+                       1105,1,46,98,99";
+        assert_eq!(outputs(compare, &[7]), vec![999]);
+        assert_eq!(outputs(compare, &[8]), vec![1000]);
+        assert_eq!(outputs(compare, &[9]), vec![1001]);
+    }
+
+    #[test]
+    fn relative_base_and_sparse_memory() {
+        // 2019 day 9: a program printing itself, reading and writing past its end
+        let quine = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+        let expected: Vec<i64> = quine.split(',').map(|v| v.parse().unwrap()).collect();
+        assert_eq!(outputs(quine, &[]), expected);
+        assert_eq!(
+            outputs("104,1125899906842624,99", &[]),
+            vec![1125899906842624]
+        );
+        assert_eq!(
+            outputs("1102,34915192,34915192,7,4,7,99,0", &[]),
+            vec![1219070632396864]
+        );
+    }
+
+    #[test]
+    fn pauses_on_input() {
+        let mut machine = Machine::parse("3,0,4,0,3,0,4,0,99").unwrap();
+        assert_eq!(machine.run().unwrap(), State::NeedsInput);
+        machine.push_input(5);
+        assert_eq!(machine.run().unwrap(), State::Output(5));
+        assert_eq!(machine.run().unwrap(), State::NeedsInput);
+        assert!(machine.clone().run_to_end(&[]).is_err());
+        assert_eq!(machine.run_to_end(&[6]).unwrap(), vec![6]);
+        assert!(Machine::parse("42").unwrap().run().is_err());
+    }
+
+    #[test]
+    fn amplifiers() {
+        // 2019 day 7, with the best phase settings of its examples
+        let serial = Machine::parse("3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0").unwrap();
+        assert_eq!(chain(&serial, &[4, 3, 2, 1, 0], 0, false).unwrap(), 43210);
+        let looped = Machine::parse(
+            "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,\
+             0,0,5",
+        )
+        .unwrap();
+        assert_eq!(
+            chain(&looped, &[9, 8, 7, 6, 5], 0, true).unwrap(),
+            139629729
+        );
+        // any other order does worse
+        assert!(chain(&looped, &[5, 6, 7, 8, 9], 0, true).unwrap() < 139629729);
+
+        // the setting and the input are all the first one gets without feedback
+        let starved = Machine::parse("3,0,3,0,3,0,4,0,99").unwrap();
+        assert!(chain(&starved, &[1], 0, false).is_err());
+    }
+}
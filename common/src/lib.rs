@@ -0,0 +1,158 @@
+pub mod bench;
+pub mod cycle;
+mod error;
+pub mod export;
+pub mod geometry;
+pub mod graph;
+mod grid;
+pub mod intcode;
+pub mod linalg;
+pub mod math;
+pub mod parallel;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod testing;
+
+pub use error::{AocError, Result};
+pub use grid::*;
+
+use std::fmt::{Debug, Display};
+
+use export::Drawing;
+use std::fs::read_to_string;
+use std::time::Instant;
+
+pub trait DayTask<T: Debug + Display + std::cmp::Eq> {
+    fn day_no(&self) -> u8;
+
+    fn run_part(&self, part: u8, lines: &Vec<String>) -> Result<T> {
+        let day = self.day_no();
+        println!("[[Day {day} - part {part}]]");
+        let now = Instant::now();
+        self.check_examples(part)?;
+        println!("[test: {}ms]", now.elapsed().as_millis());
+        let now = Instant::now();
+        let result = self.solve(part, lines, false)?;
+        println!("{result}");
+        println!("[main: {}ms]", now.elapsed().as_millis());
+        self.check_answer(part, &result)?;
+        Ok(result)
+    }
+
+    fn solve(&self, part: u8, lines: &Vec<String>, is_test: bool) -> Result<T> {
+        if part == 1 {
+            self.run_p1(lines, is_test)
+        } else {
+            self.run_p2(lines, is_test)
+        }
+    }
+
+    fn check_examples(&self, part: u8) -> Result<()> {
+        for case in 1..=self.example_count(part) {
+            self.check_example(part, case)?;
+        }
+        Ok(())
+    }
+
+    // an example input without its expected result, or the other way around, is a mistake
+    // in the day, not an example to leave out
+    fn example_count(&self, part: u8) -> usize {
+        let (inputs, results) = if part == 1 {
+            (
+                self.get_part1_test_input().len(),
+                self.get_part1_test_result().len(),
+            )
+        } else {
+            (
+                self.get_part2_test_input().len(),
+                self.get_part2_test_result().len(),
+            )
+        };
+        assert_eq!(
+            inputs,
+            results,
+            "Day {} part {part} has {inputs} example inputs but {results} expected results",
+            self.day_no()
+        );
+        inputs
+    }
+
+    // examples are numbered from 1, like in the reports
+    fn check_example(&self, part: u8, case: usize) -> Result<()> {
+        let (test_inputs, test_results) = if part == 1 {
+            (self.get_part1_test_input(), self.get_part1_test_result())
+        } else {
+            (self.get_part2_test_input(), self.get_part2_test_result())
+        };
+        let expected = &test_results[case - 1];
+        let actual = self.solve(part, &self.get_test_data(test_inputs[case - 1]), true)?;
+        if actual != *expected {
+            return Err(AocError::TestMismatch {
+                part,
+                case,
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn check_answer(&self, part: u8, answer: &T) -> Result<()> {
+        match self.get_result(part) {
+            Some(expected) if expected != *answer => Err(AocError::AnswerMismatch {
+                part,
+                expected: expected.to_string(),
+                actual: answer.to_string(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    fn get_result(&self, part: u8) -> Option<T> {
+        if part == 1 {
+            self.get_part1_result()
+        } else {
+            self.get_part2_result()
+        }
+    }
+
+    fn read_lines(&self, filename: &str) -> Result<Vec<String>> {
+        let content = read_to_string(filename).map_err(|source| AocError::MissingInput {
+            path: filename.to_string(),
+            source,
+        })?;
+        Ok(content.lines().map(String::from).collect())
+    }
+
+    // graph-shaped days can override this to be drawn with `aoc-YYYY graph DAY`
+    fn export_graph(&self, _part: u8, _lines: &Vec<String>) -> Result<Drawing> {
+        Err(AocError::NotImplemented)
+    }
+
+    // days running a program of their input can override this to list what it does, printed
+    // with `aoc-YYYY trace DAY`
+    fn trace(&self, _lines: &Vec<String>) -> Result<Vec<String>> {
+        Err(AocError::NotImplemented)
+    }
+
+    fn get_test_data(&self, input: &str) -> Vec<String> {
+        input.lines().map(String::from).collect()
+    }
+
+    fn get_part1_test_input(&self) -> Vec<&'static str>;
+
+    fn get_part2_test_input(&self) -> Vec<&'static str>;
+
+    fn get_part1_test_result(&self) -> Vec<T>;
+
+    fn get_part2_test_result(&self) -> Vec<T>;
+
+    fn run_p1(&self, lines: &Vec<String>, is_test: bool) -> Result<T>;
+
+    fn run_p2(&self, lines: &Vec<String>, is_test: bool) -> Result<T>;
+
+    fn get_part1_result(&self) -> Option<T>;
+
+    fn get_part2_result(&self) -> Option<T>;
+}
@@ -0,0 +1,111 @@
+use num::{BigInt, BigRational, One, Zero};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solution {
+    Unique(Vec<BigRational>),
+    // some equation contradicts the others
+    Inconsistent,
+    // fewer independent equations than unknowns
+    Underdetermined,
+}
+
+impl Solution {
+    // the unique solution, only when every value of it is a whole number
+    pub fn integers(&self) -> Option<Vec<BigInt>> {
+        match self {
+            Solution::Unique(values) => values
+                .iter()
+                .map(|v| v.is_integer().then(|| v.to_integer()))
+                .collect(),
+            _ => None,
+        }
+    }
+}
+
+// solves matrix * x = rhs exactly, by Gauss-Jordan elimination over rationals; there may be
+// more equations than unknowns as long as they agree
+pub fn solve<T: Clone + Into<BigInt>>(matrix: &[Vec<T>], rhs: &[T]) -> Solution {
+    let unknowns = matrix.first().map_or(0, |row| row.len());
+    let ratio = |v: &T| BigRational::from_integer(v.clone().into());
+    let mut rows: Vec<Vec<BigRational>> = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, r)| row.iter().chain([r]).map(ratio).collect())
+        .collect();
+
+    let mut rank = 0;
+    for col in 0..unknowns {
+        let Some(pivot) = (rank..rows.len()).find(|&r| !rows[r][col].is_zero()) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+        let inverse = BigRational::one() / &rows[rank][col];
+        for v in rows[rank].iter_mut() {
+            *v *= &inverse;
+        }
+        let pivot_row = rows[rank].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r == rank || row[col].is_zero() {
+                continue;
+            }
+            let factor = row[col].clone();
+            for (v, p) in row.iter_mut().zip(&pivot_row) {
+                *v -= &factor * p;
+            }
+        }
+        rank += 1;
+    }
+
+    // every row below the rank has no coefficients left, only its right-hand side
+    if rows[rank..].iter().any(|row| !row[unknowns].is_zero()) {
+        return Solution::Inconsistent;
+    }
+    if rank < unknowns {
+        return Solution::Underdetermined;
+    }
+    Solution::Unique(
+        rows.into_iter()
+            .take(unknowns)
+            .map(|row| row[unknowns].clone())
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ints(values: &[i64]) -> Option<Vec<BigInt>> {
+        Some(values.iter().map(|&v| v.into()).collect())
+    }
+
+    #[test]
+    fn unique_solutions() {
+        // 2x + y - z = 8, -3x - y + 2z = -11, -2x + y + 2z = -3
+        let matrix = vec![vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]];
+        let solution = solve(&matrix, &[8, -11, -3]);
+        assert_eq!(solution.integers(), ints(&[2, 3, -1]));
+
+        // a zero pivot that needs a row swap, and a fraction
+        let solution = solve(&[vec![0, 2], vec![3, 0]], &[1, 6]);
+        let half = BigRational::new(1.into(), 2.into());
+        assert_eq!(
+            solution,
+            Solution::Unique(vec![BigRational::from_integer(2.into()), half])
+        );
+        assert_eq!(solution.integers(), None);
+
+        // more equations than unknowns, but consistent
+        let matrix = vec![vec![1i128, 1], vec![1, -1], vec![2, 0]];
+        assert_eq!(solve(&matrix, &[5, 1, 6]).integers(), ints(&[3, 2]));
+    }
+
+    #[test]
+    fn degenerate_systems() {
+        let matrix = vec![vec![1, 2], vec![2, 4]];
+        assert_eq!(solve(&matrix, &[3, 6]), Solution::Underdetermined);
+        assert_eq!(solve(&matrix, &[3, 7]), Solution::Inconsistent);
+        let matrix = vec![vec![1, 0], vec![0, 1], vec![1, 1]];
+        assert_eq!(solve(&matrix, &[1, 1, 3]), Solution::Inconsistent);
+    }
+}
@@ -0,0 +1,158 @@
+use num::{BigInt, Integer, Signed};
+
+// number theory that works the same on i64, i128 and BigInt; with a fixed-width type the
+// intermediate values of crt stay below twice the combined modulus and the square of the
+// largest modulus, so i64 moduli are safe in i128 as long as their lcm fits with a bit to
+// spare, and BigInt never overflows
+
+// (g, x, y) with a * x + b * y = g = gcd(a, b), and g never negative
+pub fn extended_gcd<T: Integer + Signed + Clone>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+    while !r.is_zero() {
+        let q = old_r.div_floor(&r);
+        (old_r, r) = (r.clone(), old_r - q.clone() * r);
+        (old_x, x) = (x.clone(), old_x - q.clone() * x);
+        (old_y, y) = (y.clone(), old_y - q * y);
+    }
+    if old_r.is_negative() {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+// x in 0..m with a * x = 1 (mod m), None unless a and m are coprime
+pub fn mod_inverse<T: Integer + Signed + Clone>(a: T, m: T) -> Option<T> {
+    if m.is_zero() {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.mod_floor(&m), m.clone());
+    g.is_one().then(|| x.mod_floor(&m))
+}
+
+// merges x = r (mod m) congruences into a single one, (r, lcm of the moduli) with r in
+// 0..lcm; the moduli don't need to be coprime, None if the congruences contradict or a
+// modulus is 0
+pub fn crt<T: Integer + Signed + Clone>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let mut residue = T::zero();
+    let mut modulus = T::one();
+    for (r, m) in congruences {
+        if m.is_zero() {
+            return None;
+        }
+        let (g, p, _) = extended_gcd(modulus.clone(), m.clone());
+        let diff = r.clone() - residue.clone();
+        if !diff.is_multiple_of(&g) {
+            return None;
+        }
+        // modulus * p = g (mod m), so stepping modulus * k from residue reaches r
+        let step = m.clone() / g.clone();
+        let k = (diff / g).mod_floor(&step) * p.mod_floor(&step) % step.clone();
+        residue = residue + modulus.clone() * k;
+        modulus = modulus * step;
+        residue = residue.mod_floor(&modulus);
+    }
+    Some((residue, modulus))
+}
+
+// the same on i64 congruences, in BigInt whenever the combined modulus won't fit in i128
+pub fn crt_i64(congruences: &[(i64, i64)]) -> Option<(BigInt, BigInt)> {
+    if congruences.iter().any(|&(_, m)| m == 0) {
+        return None;
+    }
+    let wide: Vec<(i128, i128)> = congruences
+        .iter()
+        .map(|&(r, m)| (r as i128, m as i128))
+        .collect();
+    let lcm = wide
+        .iter()
+        .try_fold(1i128, |lcm, &(_, m)| lcm.checked_mul(m / lcm.gcd(&m)));
+    if lcm.and_then(|lcm| lcm.checked_mul(2)).is_some() {
+        return crt(&wide).map(|(r, m)| (r.into(), m.into()));
+    }
+    let big: Vec<(BigInt, BigInt)> = congruences
+        .iter()
+        .map(|&(r, m)| (r.into(), m.into()))
+        .collect();
+    crt(&big)
+}
+
+// (a * b) % m without overflowing, through u128
+pub fn mod_mul(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+// there is nothing to reduce modulo 0, so m must be positive
+pub fn mod_pow(base: u64, mut exp: u64, m: u64) -> u64 {
+    assert!(m != 0, "mod_pow needs a positive modulus");
+    let mut result = 1 % m;
+    let mut base = base % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mod_mul(result, base, m);
+        }
+        base = mod_mul(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_inverse() {
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        assert_eq!(extended_gcd(-4, 6).0, 2);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 10), None);
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(7, 0, 1), 0);
+        // would overflow u64 with a plain multiplication
+        let p = 18446744073709551557;
+        assert_eq!(mod_pow(3, p - 1, p), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "mod_pow needs a positive modulus")]
+    fn mod_pow_by_zero() {
+        mod_pow(2, 3, 0);
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // the moduli share factors
+        assert_eq!(crt(&[(2i64, 4), (0, 6)]), Some((6, 12)));
+        assert_eq!(crt(&[(1i64, 4), (0, 6)]), None);
+        assert_eq!(crt(&[(1i64, 4), (0, 0)]), None);
+        assert_eq!(crt_i64(&[(0, 0), (1, 3)]), None);
+        assert_eq!(mod_inverse(3, 0), None);
+        // the 2020 day 13 bus example: bus m leaves at t + offset
+        let buses = [(7, 0), (13, 1), (59, 4), (31, 6), (19, 7)];
+        let congruences: Vec<(i64, i64)> = buses.iter().map(|&(m, o)| (-o, m)).collect();
+        assert_eq!(crt(&congruences).unwrap().0, 1068781);
+    }
+
+    #[test]
+    fn wide_moduli() {
+        let primes = [
+            1_000_000_007i64,
+            998_244_353,
+            1_000_000_009,
+            2_147_483_647,
+            4_294_967_291,
+        ];
+        let congruences: Vec<(i64, i64)> = primes.iter().map(|&p| (p - 1, p)).collect();
+        // the lcm of all five needs more than 128 bits, x = -1 modulo each of them
+        let (r, m) = crt_i64(&congruences).unwrap();
+        assert_eq!(r, m.clone() - 1);
+        assert!(m.bits() > 128);
+        let (r, m) = crt_i64(&congruences[..3]).unwrap();
+        assert_eq!(r + 1, m);
+    }
+}
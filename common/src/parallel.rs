@@ -0,0 +1,165 @@
+use std::path::Path;
+use std::thread;
+use std::time::Instant;
+
+use crossbeam_channel::unbounded;
+
+use crate::runner::{catch_panic, format_duration, Args, Outcome, Registry, Runnable};
+use crate::{AocError, Result};
+
+// same worker pool as 2023 day 5: a shared job queue and a result channel, one job per
+// day and part, so a slow part 2 doesn't hold back the rest of its day
+pub fn run(
+    registry: &Registry,
+    args: &Args,
+    input_dir: &Path,
+    jobs: usize,
+) -> std::result::Result<Vec<Outcome>, String> {
+    let start = Instant::now();
+    let mut outcomes = vec![];
+    let mut inputs = vec![];
+    for day in args.select_days(registry)? {
+        let task = registry[&day].as_ref();
+        let input = args.input_path(input_dir, day);
+        match task.read_lines(&input.to_string_lossy()) {
+            Ok(lines) => inputs.push((task, lines)),
+            Err(err) => outcomes.push(Outcome {
+                day,
+                part: None,
+                result: Err(err),
+                elapsed: None,
+            }),
+        }
+    }
+
+    let (tx_job, rx_job) = unbounded();
+    let (tx_outcome, rx_outcome) = unbounded();
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let rx_job = rx_job.clone();
+            let tx_outcome = tx_outcome.clone();
+            scope.spawn(move || {
+                while let Ok((task, part, lines)) = rx_job.recv() {
+                    tx_outcome.send(run_part(task, part, lines)).unwrap();
+                }
+            });
+        }
+        drop(tx_outcome);
+
+        for (task, lines) in &inputs {
+            for part in args.parts() {
+                tx_job.send((*task, part, lines)).unwrap();
+            }
+        }
+        drop(tx_job);
+
+        for outcome in rx_outcome {
+            let status = match &outcome.result {
+                Ok(_) => "done",
+                Err(_) => "failed",
+            };
+            println!(
+                "Day {:>2} part {} {status}",
+                outcome.day,
+                outcome.part.unwrap_or_default()
+            );
+            outcomes.push(outcome);
+        }
+    });
+    outcomes.sort_by_key(|o| (o.day, o.part));
+    println!(
+        "Ran {} days on {jobs} threads in {}",
+        inputs.len(),
+        format_duration(start.elapsed())
+    );
+    Ok(outcomes)
+}
+
+// the examples are checked first but only solving the real input is timed
+fn run_part(task: &dyn Runnable, part: u8, lines: &Vec<String>) -> Outcome {
+    let mut elapsed = None;
+    let result = catch_panic(|| -> Result<String> {
+        task.check_examples(part)?;
+        let now = Instant::now();
+        let answer = task.solve(part, lines);
+        if !matches!(answer, Err(AocError::NotImplemented)) {
+            elapsed = Some(now.elapsed());
+        }
+        answer
+    });
+    Outcome {
+        day: task.day_no(),
+        part: Some(part),
+        result,
+        elapsed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{build_registry, register};
+    use crate::DayTask;
+    use std::fs;
+
+    struct Sum(u8);
+
+    impl DayTask<i64> for Sum {
+        fn day_no(&self) -> u8 {
+            self.0
+        }
+        fn get_part1_test_input(&self) -> Vec<&'static str> {
+            vec!["1\n2"]
+        }
+        fn get_part2_test_input(&self) -> Vec<&'static str> {
+            vec![]
+        }
+        fn get_part1_test_result(&self) -> Vec<i64> {
+            vec![3]
+        }
+        fn get_part2_test_result(&self) -> Vec<i64> {
+            vec![]
+        }
+        fn run_p1(&self, lines: &Vec<String>, _is_test: bool) -> Result<i64> {
+            lines.iter().map(|l| Ok(l.parse::<i64>()?)).sum()
+        }
+        fn run_p2(&self, _lines: &Vec<String>, _is_test: bool) -> Result<i64> {
+            Err(AocError::NotImplemented)
+        }
+        fn get_part1_result(&self) -> Option<i64> {
+            None
+        }
+        fn get_part2_result(&self) -> Option<i64> {
+            None
+        }
+    }
+
+    #[test]
+    fn outcomes_are_sorted_and_timed() {
+        let dir = std::env::temp_dir().join(format!("aoc-parallel-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for day in 1..=3 {
+            fs::write(dir.join(format!("d{day}.txt")), format!("{day}\n{day}\nx")).unwrap();
+        }
+        fs::write(dir.join("d4.txt"), "4\n4").unwrap();
+        let registry = build_registry((1..=5).map(|day| register(Sum(day))).collect());
+        let args = Args::parse(["all".to_string()]).unwrap();
+        let outcomes = run(&registry, &args, &dir, 3).unwrap();
+
+        let summary: Vec<(u8, Option<u8>, bool, bool)> = outcomes
+            .iter()
+            .map(|o| (o.day, o.part, o.is_failure(), o.elapsed.is_some()))
+            .collect();
+        let mut expected = vec![];
+        for day in 1..=3 {
+            expected.push((day, Some(1), true, true));
+            expected.push((day, Some(2), false, false));
+        }
+        expected.push((4, Some(1), false, true));
+        expected.push((4, Some(2), false, false));
+        // day 5 has no input
+        expected.push((5, None, true, false));
+        assert_eq!(summary, expected);
+        assert_eq!(outcomes[6].result.as_ref().unwrap(), "8");
+    }
+}
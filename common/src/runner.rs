@@ -0,0 +1,712 @@
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Display};
+use std::marker::PhantomData;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+use crate::export::{Drawing, Format};
+use crate::{bench, parallel, scaffold, AocError, DayTask, Result};
+
+const USAGE: &str =
+    "Usage: aoc-YYYY [bench | scaffold | graph | trace] [DAY | FROM-TO | all] [--part 1|2] [--input PATH]
+
+  DAY         run a single day, e.g. `aoc-2024 16`
+  FROM-TO     run a range of days, e.g. `aoc-2024 1-10`
+  all         run every registered day, in parallel on all CPUs unless --jobs is given
+  (no day)    run the latest registered day
+  bench       time the selected days instead of running them once, e.g. `aoc-2024 bench 20`
+  scaffold    create src/dN.rs from the template, register it and fetch its input,
+              e.g. `aoc-2024 scaffold 21`; downloads need AOC_SESSION set to the session
+              cookie and are cached in AOC_CACHE_DIR (default: ~/.cache/aoc)
+  graph       print the graph a day parsed from its input, e.g. `aoc-2023 graph 20 > d20.dot`
+  trace       print the program a day runs from its input and its first steps,
+              e.g. `aoc-2024 trace 17`
+
+Options:
+  -p, --part 1|2      run only one part (default: both)
+  -i, --input PATH    read puzzle input from PATH instead of dN.txt (single day only)
+  -j, --jobs N        run the selected days on N threads and print a table of timings
+  -n, --runs N        bench: number of timed runs per part (default: 10)
+  -w, --warmup N      bench: number of untimed runs before timing (default: 2)
+  -f, --from DIR      scaffold: copy the input from DIR/dN.txt instead of downloading it
+  -F, --format FMT    graph: dot or mermaid (default: dot); --part picks the part's graph";
+
+// the short and long forms of the options taking a value
+const OPTIONS: [(&str, &str); 7] = [
+    ("-p", "--part"),
+    ("-i", "--input"),
+    ("-j", "--jobs"),
+    ("-n", "--runs"),
+    ("-w", "--warmup"),
+    ("-f", "--from"),
+    ("-F", "--format"),
+];
+
+// DayTask is generic over its answer type, so every day is wrapped in a type-erased
+// Runnable to be able to keep all of them in a single table keyed by day number
+pub trait Runnable: Sync {
+    fn day_no(&self) -> u8;
+    fn read_lines(&self, filename: &str) -> Result<Vec<String>>;
+    fn run_part(&self, part: u8, lines: &Vec<String>) -> Result<String>;
+    fn check_examples(&self, part: u8) -> Result<()>;
+    // solves and checks a part without printing anything, used for timing
+    fn solve(&self, part: u8, lines: &Vec<String>) -> Result<String>;
+    fn export_graph(&self, part: u8, lines: &Vec<String>) -> Result<Drawing>;
+    fn trace(&self, lines: &Vec<String>) -> Result<Vec<String>>;
+}
+
+struct Registered<T, D> {
+    task: D,
+    _answer: PhantomData<fn() -> T>,
+}
+
+impl<T, D> Runnable for Registered<T, D>
+where
+    T: Debug + Display + Eq,
+    D: DayTask<T> + Sync,
+{
+    fn day_no(&self) -> u8 {
+        self.task.day_no()
+    }
+
+    fn read_lines(&self, filename: &str) -> Result<Vec<String>> {
+        self.task.read_lines(filename)
+    }
+
+    fn run_part(&self, part: u8, lines: &Vec<String>) -> Result<String> {
+        self.task
+            .run_part(part, lines)
+            .map(|answer| answer.to_string())
+    }
+
+    fn check_examples(&self, part: u8) -> Result<()> {
+        self.task.check_examples(part)
+    }
+
+    fn solve(&self, part: u8, lines: &Vec<String>) -> Result<String> {
+        let answer = self.task.solve(part, lines, false)?;
+        self.task.check_answer(part, &answer)?;
+        Ok(answer.to_string())
+    }
+
+    fn export_graph(&self, part: u8, lines: &Vec<String>) -> Result<Drawing> {
+        self.task.export_graph(part, lines)
+    }
+
+    fn trace(&self, lines: &Vec<String>) -> Result<Vec<String>> {
+        self.task.trace(lines)
+    }
+}
+
+pub fn register<T, D>(task: D) -> Box<dyn Runnable>
+where
+    T: Debug + Display + Eq + 'static,
+    D: DayTask<T> + Sync + 'static,
+{
+    Box::new(Registered {
+        task,
+        _answer: PhantomData,
+    })
+}
+
+pub type Registry = BTreeMap<u8, Box<dyn Runnable>>;
+
+pub fn build_registry(tasks: Vec<Box<dyn Runnable>>) -> Registry {
+    let mut registry = Registry::new();
+    for task in tasks {
+        let day = task.day_no();
+        if registry.insert(day, task).is_some() {
+            panic!("Day {day} is registered twice");
+        }
+    }
+    registry
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DaySelection {
+    Latest,
+    Single(u8),
+    Range(u8, u8),
+    All,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    // more than one job runs the days on a thread pool
+    Run { jobs: usize },
+    Bench { runs: usize, warmup: usize },
+    Scaffold { day: u8, from: Option<String> },
+    Graph { format: Format },
+    Trace,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Args {
+    pub command: Command,
+    pub days: DaySelection,
+    pub part: Option<u8>,
+    pub input: Option<String>,
+}
+
+impl Args {
+    pub fn parse<I>(args: I) -> std::result::Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut days = None;
+        let mut part = None;
+        let mut input = None;
+        let mut runs = 10;
+        let mut warmup = 2;
+        let mut jobs = None;
+        let mut from = None;
+        let mut format = Format::Dot;
+        let mut args = args.into_iter().peekable();
+        let keyword =
+            args.next_if(|arg| ["bench", "scaffold", "graph", "trace"].contains(&arg.as_str()));
+        // the long form of every option given, to check they all belong to the command
+        let mut given = vec![];
+        while let Some(arg) = args.next() {
+            if let Some(&(_, long)) = OPTIONS
+                .iter()
+                .find(|(short, long)| arg == *short || arg == *long)
+            {
+                given.push(long);
+            }
+            match arg.as_str() {
+                "-p" | "--part" => {
+                    let value = args.next().ok_or("--part needs a value")?;
+                    part = match value.as_str() {
+                        "1" => Some(1),
+                        "2" => Some(2),
+                        _ => return Err(format!("Invalid part: {value}")),
+                    };
+                }
+                "-i" | "--input" => {
+                    input = Some(args.next().ok_or("--input needs a value")?);
+                }
+                "-n" | "--runs" => {
+                    let value = args.next().ok_or("--runs needs a value")?;
+                    runs = match value.parse() {
+                        Ok(runs) if runs > 0 => runs,
+                        _ => return Err(format!("Invalid number of runs: {value}")),
+                    };
+                }
+                "-w" | "--warmup" => {
+                    let value = args.next().ok_or("--warmup needs a value")?;
+                    warmup = value
+                        .parse()
+                        .map_err(|_| format!("Invalid number of warmup runs: {value}"))?;
+                }
+                "-j" | "--jobs" => {
+                    let value = args.next().ok_or("--jobs needs a value")?;
+                    jobs = match value.parse() {
+                        Ok(jobs) if jobs > 0 => Some(jobs),
+                        _ => return Err(format!("Invalid number of jobs: {value}")),
+                    };
+                }
+                "-f" | "--from" => {
+                    from = Some(args.next().ok_or("--from needs a value")?);
+                }
+                "-F" | "--format" => {
+                    let value = args.next().ok_or("--format needs a value")?;
+                    format = match value.as_str() {
+                        "dot" => Format::Dot,
+                        "mermaid" => Format::Mermaid,
+                        _ => return Err(format!("Invalid graph format: {value}")),
+                    };
+                }
+                "-h" | "--help" => return Err(String::new()),
+                _ if days.is_none() => days = Some(parse_days(&arg)?),
+                _ => return Err(format!("Unexpected argument: {arg}")),
+            }
+        }
+        let allowed: &[&str] = match keyword.as_deref() {
+            None => &["--part", "--input", "--jobs"],
+            Some("bench") => &["--part", "--input", "--runs", "--warmup"],
+            Some("scaffold") => &["--from"],
+            Some("graph") => &["--part", "--input", "--format"],
+            Some(_) => &["--input"],
+        };
+        if let Some(option) = given.iter().find(|option| !allowed.contains(option)) {
+            return Err(match keyword.as_deref() {
+                Some(keyword) => format!("{option} can't be used with {keyword}"),
+                None => format!("{option} can't be used when running days"),
+            });
+        }
+        let days = days.unwrap_or(DaySelection::Latest);
+        if input.is_some() && !matches!(days, DaySelection::Single(_) | DaySelection::Latest) {
+            return Err("--input can only be used with a single day".to_string());
+        }
+        let command = match keyword.as_deref() {
+            Some("bench") => Command::Bench { runs, warmup },
+            Some("graph") => match days {
+                DaySelection::Single(_) | DaySelection::Latest => Command::Graph { format },
+                _ => return Err("graph needs a single day".to_string()),
+            },
+            Some("trace") => match days {
+                DaySelection::Single(_) | DaySelection::Latest => Command::Trace,
+                _ => return Err("trace needs a single day".to_string()),
+            },
+            Some(_) => match days {
+                DaySelection::Single(day) => Command::Scaffold { day, from },
+                _ => return Err("scaffold needs a single day".to_string()),
+            },
+            None => Command::Run {
+                jobs: jobs.unwrap_or(match days {
+                    DaySelection::All => thread::available_parallelism().map_or(1, |n| n.get()),
+                    _ => 1,
+                }),
+            },
+        };
+        Ok(Self {
+            command,
+            days,
+            part,
+            input,
+        })
+    }
+}
+
+fn parse_days(arg: &str) -> std::result::Result<DaySelection, String> {
+    let parse_day = |s: &str| match s.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("Invalid day: {s}")),
+    };
+    if arg == "all" {
+        return Ok(DaySelection::All);
+    }
+    match arg.split_once('-') {
+        Some((from, to)) => {
+            let (from, to) = (parse_day(from)?, parse_day(to)?);
+            if from > to {
+                return Err(format!("Invalid day range: {arg}"));
+            }
+            Ok(DaySelection::Range(from, to))
+        }
+        None => Ok(DaySelection::Single(parse_day(arg)?)),
+    }
+}
+
+pub struct Outcome {
+    pub day: u8,
+    // None when the day failed before any of its parts could run, e.g. on a missing input
+    pub part: Option<u8>,
+    pub result: Result<String>,
+    // only measured when the days run in parallel, a sequential run prints its own timings
+    pub elapsed: Option<Duration>,
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        !matches!(self.result, Ok(_) | Err(AocError::NotImplemented))
+    }
+}
+
+impl Args {
+    // errors rather than selecting no day at all
+    pub fn select_days(&self, registry: &Registry) -> std::result::Result<Vec<u8>, String> {
+        let days: Vec<u8> = match self.days {
+            DaySelection::Latest => registry.keys().last().into_iter().copied().collect(),
+            DaySelection::Single(day) => {
+                if !registry.contains_key(&day) {
+                    return Err(format!("Day {day} is not registered"));
+                }
+                vec![day]
+            }
+            DaySelection::Range(from, to) => registry.range(from..=to).map(|(&d, _)| d).collect(),
+            DaySelection::All => registry.keys().copied().collect(),
+        };
+        match (days.is_empty(), &self.days) {
+            (true, DaySelection::Range(from, to)) => {
+                Err(format!("No day from {from} to {to} is registered"))
+            }
+            (true, _) => Err("No day is registered".to_string()),
+            (false, _) => Ok(days),
+        }
+    }
+
+    pub fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+
+    pub fn input_path(&self, input_dir: &Path, day: u8) -> PathBuf {
+        match &self.input {
+            Some(path) => PathBuf::from(path),
+            None => input_dir.join(format!("d{day}.txt")),
+        }
+    }
+}
+
+pub fn run(
+    registry: &Registry,
+    args: &Args,
+    input_dir: &Path,
+) -> std::result::Result<Vec<Outcome>, String> {
+    let parts = args.parts();
+    let mut outcomes = vec![];
+    for day in args.select_days(registry)? {
+        let input = args.input_path(input_dir, day);
+        outcomes.extend(run_day(registry[&day].as_ref(), &parts, &input));
+    }
+    Ok(outcomes)
+}
+
+pub fn run_day(task: &dyn Runnable, parts: &[u8], input: &Path) -> Vec<Outcome> {
+    let day = task.day_no();
+    let lines = match task.read_lines(&input.to_string_lossy()) {
+        Ok(lines) => lines,
+        Err(err) => {
+            return vec![Outcome {
+                day,
+                part: None,
+                result: Err(err),
+                elapsed: None,
+            }]
+        }
+    };
+    parts
+        .iter()
+        .map(|&part| Outcome {
+            day,
+            part: Some(part),
+            result: catch_panic(|| task.run_part(part, &lines)),
+            elapsed: None,
+        })
+        .collect()
+}
+
+pub(crate) fn catch_panic<R>(f: impl FnOnce() -> Result<R>) -> Result<R> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let msg = if let Some(msg) = payload.downcast_ref::<&str>() {
+            msg.to_string()
+        } else if let Some(msg) = payload.downcast_ref::<String>() {
+            msg.clone()
+        } else {
+            "unknown reason".to_string()
+        };
+        Err(AocError::Panic(msg))
+    })
+}
+
+pub(crate) fn format_duration(d: Duration) -> String {
+    let micros = d.as_micros();
+    if micros < 1000 {
+        format!("{micros}µs")
+    } else if micros < 1_000_000 {
+        format!("{:.1}ms", micros as f64 / 1000.0)
+    } else {
+        format!("{:.2}s", micros as f64 / 1_000_000.0)
+    }
+}
+
+pub fn print_report(outcomes: &[Outcome]) {
+    println!();
+    println!("[[Report]]");
+    let timed = outcomes.iter().any(|o| o.elapsed.is_some());
+    for outcome in outcomes {
+        let part = outcome
+            .part
+            .map_or("-".to_string(), |part| part.to_string());
+        let (status, detail) = match &outcome.result {
+            Ok(answer) => ("ok", answer.clone()),
+            Err(AocError::NotImplemented) => ("todo", String::new()),
+            Err(err) => ("FAIL", err.to_string()),
+        };
+        let time = match outcome.elapsed {
+            Some(elapsed) => format!("{:>9}  ", format_duration(elapsed)),
+            None if timed => format!("{:>9}  ", "-"),
+            None => String::new(),
+        };
+        println!(
+            "Day {:>2} part {part}: {time}{}",
+            outcome.day,
+            format!("{status:<4} {detail}").trim_end()
+        );
+    }
+    let failed = outcomes.iter().filter(|o| o.is_failure()).count();
+    let todo = outcomes
+        .iter()
+        .filter(|o| matches!(o.result, Err(AocError::NotImplemented)))
+        .count();
+    println!(
+        "{} ok, {todo} not implemented, {failed} failed",
+        outcomes.len() - failed - todo
+    );
+    if timed {
+        print_slowest(outcomes);
+    }
+}
+
+fn print_slowest(outcomes: &[Outcome]) {
+    let mut per_day = BTreeMap::<u8, Duration>::new();
+    for outcome in outcomes {
+        *per_day.entry(outcome.day).or_default() += outcome.elapsed.unwrap_or_default();
+    }
+    let total: Duration = per_day.values().sum();
+    println!("Total: {}", format_duration(total));
+    let mut slowest: Vec<(u8, Duration)> = per_day.into_iter().collect();
+    slowest.sort_by_key(|&(day, elapsed)| (std::cmp::Reverse(elapsed), day));
+    let slowest: Vec<String> = slowest
+        .iter()
+        .take(5)
+        .map(|&(day, elapsed)| {
+            let share = elapsed.as_secs_f64() / total.as_secs_f64().max(f64::EPSILON) * 100.0;
+            format!("day {day} {} ({share:.0}%)", format_duration(elapsed))
+        })
+        .collect();
+    println!("Slowest: {}", slowest.join(", "));
+}
+
+// crate_dir is the year's manifest dir: its src/ holds the days, and the dN.txt inputs live next to it
+pub fn main(registry: Registry, year: u16, crate_dir: &str) {
+    let crate_dir = Path::new(crate_dir);
+    let args = Args::parse(std::env::args().skip(1)).unwrap_or_else(|msg| usage_error(&msg));
+    let result = match &args.command {
+        Command::Run { jobs: 1 } => run(&registry, &args, crate_dir),
+        Command::Run { jobs } => parallel::run(&registry, &args, crate_dir, *jobs),
+        Command::Bench { runs, warmup } => bench::run(&registry, &args, crate_dir, *runs, *warmup),
+        Command::Scaffold { day, from } => {
+            if let Err(msg) = scaffold::run(year, crate_dir, *day, from.as_deref()) {
+                eprintln!("Scaffolding day {day} failed: {msg}");
+                process::exit(1);
+            }
+            return;
+        }
+        Command::Graph { format } => {
+            if let Err(msg) = print_graph(&registry, &args, crate_dir, *format) {
+                eprintln!("Drawing the graph failed: {msg}");
+                process::exit(1);
+            }
+            return;
+        }
+        Command::Trace => {
+            if let Err(msg) = print_trace(&registry, &args, crate_dir) {
+                eprintln!("Tracing failed: {msg}");
+                process::exit(1);
+            }
+            return;
+        }
+    };
+    match result {
+        Ok(outcomes) => {
+            print_report(&outcomes);
+            if outcomes.iter().any(|o| o.is_failure()) {
+                process::exit(1);
+            }
+        }
+        Err(msg) => usage_error(&msg),
+    }
+}
+
+fn print_graph(
+    registry: &Registry,
+    args: &Args,
+    input_dir: &Path,
+    format: Format,
+) -> std::result::Result<(), String> {
+    let day = args.select_days(registry)?[0];
+    let task = registry[&day].as_ref();
+    let input = args.input_path(input_dir, day);
+    let drawing = task
+        .read_lines(&input.to_string_lossy())
+        .and_then(|lines| task.export_graph(args.part.unwrap_or(1), &lines))
+        .map_err(|err| match err {
+            AocError::NotImplemented => format!("day {day} has no graph to draw"),
+            err => err.to_string(),
+        })?;
+    print!("{}", drawing.render(format));
+    Ok(())
+}
+
+fn print_trace(
+    registry: &Registry,
+    args: &Args,
+    input_dir: &Path,
+) -> std::result::Result<(), String> {
+    let day = args.select_days(registry)?[0];
+    let task = registry[&day].as_ref();
+    let input = args.input_path(input_dir, day);
+    let trace = task
+        .read_lines(&input.to_string_lossy())
+        .and_then(|lines| task.trace(&lines))
+        .map_err(|err| match err {
+            AocError::NotImplemented => format!("day {day} has no program to trace"),
+            err => err.to_string(),
+        })?;
+    for line in trace {
+        println!("{line}");
+    }
+    Ok(())
+}
+
+fn usage_error(msg: &str) -> ! {
+    if msg.is_empty() {
+        println!("{USAGE}");
+        process::exit(0);
+    }
+    eprintln!("{msg}\n\n{USAGE}");
+    process::exit(2);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> std::result::Result<Args, String> {
+        Args::parse(args.split_whitespace().map(String::from))
+    }
+
+    struct Day(u8);
+
+    impl DayTask<i64> for Day {
+        fn day_no(&self) -> u8 {
+            self.0
+        }
+        fn get_part1_test_input(&self) -> Vec<&'static str> {
+            vec![]
+        }
+        fn get_part2_test_input(&self) -> Vec<&'static str> {
+            vec![]
+        }
+        fn get_part1_test_result(&self) -> Vec<i64> {
+            vec![]
+        }
+        fn get_part2_test_result(&self) -> Vec<i64> {
+            vec![]
+        }
+        fn run_p1(&self, _lines: &Vec<String>, _is_test: bool) -> Result<i64> {
+            Err(AocError::NotImplemented)
+        }
+        fn run_p2(&self, _lines: &Vec<String>, _is_test: bool) -> Result<i64> {
+            Err(AocError::NotImplemented)
+        }
+        fn get_part1_result(&self) -> Option<i64> {
+            None
+        }
+        fn get_part2_result(&self) -> Option<i64> {
+            None
+        }
+    }
+
+    #[test]
+    fn runs() {
+        assert_eq!(
+            parse("").unwrap(),
+            Args {
+                command: Command::Run { jobs: 1 },
+                days: DaySelection::Latest,
+                part: None,
+                input: None,
+            }
+        );
+        assert_eq!(
+            parse("7 -p 2 --input x.txt").unwrap(),
+            Args {
+                command: Command::Run { jobs: 1 },
+                days: DaySelection::Single(7),
+                part: Some(2),
+                input: Some("x.txt".to_string()),
+            }
+        );
+        let args = parse("3-9 -j 4").unwrap();
+        assert_eq!(args.command, Command::Run { jobs: 4 });
+        assert_eq!(args.days, DaySelection::Range(3, 9));
+        assert_eq!(parse("all").unwrap().days, DaySelection::All);
+    }
+
+    #[test]
+    fn subcommands() {
+        assert_eq!(
+            parse("bench 20 -n 5 -w 0 -p 1").unwrap().command,
+            Command::Bench { runs: 5, warmup: 0 }
+        );
+        assert_eq!(
+            parse("scaffold 21 --from in").unwrap().command,
+            Command::Scaffold {
+                day: 21,
+                from: Some("in".to_string())
+            }
+        );
+        assert_eq!(
+            parse("graph 20 -F mermaid -p 2").unwrap().command,
+            Command::Graph {
+                format: Format::Mermaid
+            }
+        );
+        assert_eq!(parse("trace 17 -i x.txt").unwrap().command, Command::Trace);
+        assert_eq!(parse("--help"), Err(String::new()));
+    }
+
+    #[test]
+    fn invalid_values() {
+        for args in [
+            "0",
+            "26",
+            "9-3",
+            "1-x",
+            "1 2",
+            "-p 3",
+            "-p",
+            "-j 0",
+            "bench -n 0",
+            "graph -F svg",
+        ] {
+            assert!(parse(args).is_err(), "{args}");
+        }
+        assert!(parse("1-5 -i x.txt").is_err());
+        assert!(parse("scaffold").is_err());
+        assert!(parse("graph all").is_err());
+        assert!(parse("trace 1-3").is_err());
+    }
+
+    #[test]
+    fn options_belong_to_their_command() {
+        for args in [
+            "-n 3",
+            "--warmup 1",
+            "--from in",
+            "-F dot",
+            "bench -j 2",
+            "bench --from in",
+            "scaffold 5 -p 1",
+            "scaffold 5 -i x.txt",
+            "graph 5 --jobs 2",
+            "graph 5 -n 3",
+            "trace 17 -p 1",
+            "trace 17 -F dot",
+        ] {
+            assert!(parse(args).is_err(), "{args}");
+        }
+        assert_eq!(
+            parse("--format dot").unwrap_err(),
+            "--format can't be used when running days"
+        );
+        assert_eq!(
+            parse("bench -j 2").unwrap_err(),
+            "--jobs can't be used with bench"
+        );
+    }
+
+    #[test]
+    fn day_selection() {
+        let registry = build_registry([3, 5, 8].map(|day| register(Day(day))).into());
+        let select = |args: &str| parse(args).unwrap().select_days(&registry);
+        assert_eq!(select(""), Ok(vec![8]));
+        assert_eq!(select("5"), Ok(vec![5]));
+        assert_eq!(select("4-8"), Ok(vec![5, 8]));
+        assert_eq!(select("all"), Ok(vec![3, 5, 8]));
+        assert!(select("4").is_err());
+        assert_eq!(
+            select("6-7"),
+            Err("No day from 6 to 7 is registered".to_string())
+        );
+        assert!(parse("all").unwrap().select_days(&Registry::new()).is_err());
+    }
+}
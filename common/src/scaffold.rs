@@ -0,0 +1,415 @@
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+const TEMPLATE: &str = "d_template.rs";
+const DEFAULT_URL: &str = "https://adventofcode.com";
+// the puzzle server asks automated tools to identify themselves
+const USER_AGENT: &str = "aoc-common scaffold (input cache, one download per day)";
+
+pub trait Downloader {
+    fn download(&self, year: u16, day: u8) -> io::Result<String>;
+}
+
+// fetches from the puzzle server with the session cookie of a logged-in browser
+pub struct HttpDownloader {
+    pub base_url: String,
+    pub session: String,
+}
+
+impl Downloader for HttpDownloader {
+    fn download(&self, year: u16, day: u8) -> io::Result<String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        // the cookie goes through curl's config on stdin so it never shows up in `ps`
+        let mut curl = Command::new("curl")
+            .args(["--fail", "--silent", "--show-error", "--config", "-"])
+            .args(["--user-agent", USER_AGENT])
+            .arg(&url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        if let Some(mut stdin) = curl.stdin.take() {
+            writeln!(stdin, "cookie = \"session={}\"", self.session)?;
+        }
+        let output = curl.wait_with_output()?;
+        if !output.status.success() {
+            return Err(io::Error::other(format!(
+                "GET {url}: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        String::from_utf8(output.stdout).map_err(io::Error::other)
+    }
+}
+
+// copies dN.txt files from a local directory, for tests and machines without network
+pub struct DirDownloader {
+    pub dir: PathBuf,
+}
+
+impl Downloader for DirDownloader {
+    fn download(&self, _year: u16, day: u8) -> io::Result<String> {
+        fs::read_to_string(self.dir.join(input_file(day)))
+    }
+}
+
+// downloaded inputs are kept outside of the repo, so every day is fetched only once
+pub struct InputCache {
+    pub dir: PathBuf,
+    pub year: u16,
+}
+
+impl InputCache {
+    pub fn new(root: &Path, year: u16) -> Self {
+        Self {
+            dir: root.join(year.to_string()),
+            year,
+        }
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(input_file(day))
+    }
+
+    pub fn fetch(&self, day: u8, downloader: &dyn Downloader) -> io::Result<PathBuf> {
+        let path = self.path(day);
+        if !path.exists() {
+            let input = downloader.download(self.year, day)?;
+            fs::create_dir_all(&self.dir)?;
+            fs::write(&path, input)?;
+        }
+        Ok(path)
+    }
+}
+
+fn input_file(day: u8) -> String {
+    format!("d{day}.txt")
+}
+
+fn default_cache_root() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os("AOC_CACHE_DIR") {
+        return Some(dir.into());
+    }
+    if let Some(dir) = env::var_os("XDG_CACHE_HOME") {
+        return Some(Path::new(&dir).join("aoc"));
+    }
+    env::var_os("HOME").map(|home| Path::new(&home).join(".cache").join("aoc"))
+}
+
+pub fn set_day_no(template: &str, day: u8) -> Option<String> {
+    let start = template.find("fn day_no(")?;
+    let body = start + template[start..].find('{')? + 1;
+    let end = body + template[body..].find('}')?;
+    Some(format!(
+        "{}\n        {day}\n    {}",
+        &template[..body],
+        &template[end..]
+    ))
+}
+
+// the lines that make a day known to main.rs: the module, its registration and its tests
+struct Entry {
+    prefix: &'static str,
+    suffix: &'static str,
+    // modules are sorted by name like rustfmt does, the lists by day number
+    key: fn(u8) -> String,
+}
+
+const ENTRIES: [Entry; 3] = [
+    Entry {
+        prefix: "mod d",
+        suffix: ";",
+        key: |day| format!("d{day}"),
+    },
+    Entry {
+        prefix: "register(d",
+        suffix: "::Task),",
+        key: |day| format!("{day:02}"),
+    },
+    Entry {
+        prefix: "d",
+        suffix: ",",
+        key: |day| format!("{day:02}"),
+    },
+];
+
+pub fn register_day(main_rs: &str, day: u8) -> Option<String> {
+    let mut lines: Vec<String> = main_rs.lines().map(String::from).collect();
+    for Entry {
+        prefix,
+        suffix,
+        key,
+    } in ENTRIES
+    {
+        let entries: Vec<(usize, u8, bool)> = lines
+            .iter()
+            .enumerate()
+            .filter_map(|(i, line)| {
+                let line = line.trim();
+                let (line, commented) = match line.strip_prefix("// ") {
+                    Some(line) => (line, true),
+                    None => (line, false),
+                };
+                let entry_day = line.strip_prefix(prefix)?.strip_suffix(suffix)?;
+                Some((i, entry_day.parse().ok()?, commented))
+            })
+            .collect();
+        // a placeholder left commented out only needs to be enabled
+        if let Some(&(i, _, commented)) = entries.iter().find(|e| e.1 == day) {
+            if commented {
+                lines[i] = lines[i].replacen("// ", "", 1);
+            }
+            continue;
+        }
+        let first = entries.first()?.0;
+        let indent: String = lines[first]
+            .chars()
+            .take_while(|c| c.is_whitespace())
+            .collect();
+        let at = entries
+            .iter()
+            .filter(|e| key(e.1) < key(day))
+            .map(|e| e.0 + 1)
+            .max()
+            .unwrap_or(first);
+        lines.insert(at, format!("{indent}{prefix}{day}{suffix}"));
+    }
+    Some(lines.join("\n") + "\n")
+}
+
+pub fn create_day(crate_dir: &Path, day: u8) -> Result<(), String> {
+    let src_dir = crate_dir.join("src");
+    let source = src_dir.join(format!("d{day}.rs"));
+    if source.exists() {
+        return Err(format!("{} already exists", source.display()));
+    }
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|err| format!("cannot read {}: {err}", path.display()))
+    };
+    let write = |path: &Path, content: String| {
+        fs::write(path, content).map_err(|err| format!("cannot write {}: {err}", path.display()))
+    };
+
+    let template_path = src_dir.join(TEMPLATE);
+    let template = set_day_no(&read(&template_path)?, day)
+        .ok_or_else(|| format!("no day_no() to fill in {}", template_path.display()))?;
+    let main_path = src_dir.join("main.rs");
+    let main_rs = register_day(&read(&main_path)?, day)
+        .ok_or_else(|| format!("no day list to add day {day} to in {}", main_path.display()))?;
+    write(&source, template)?;
+    println!("Created {}", source.display());
+    write(&main_path, main_rs)?;
+    println!("Registered day {day} in {}", main_path.display());
+    Ok(())
+}
+
+// a missing input is not fatal: the puzzle may not be unlocked yet when the day is scaffolded
+pub fn fetch_input(
+    crate_dir: &Path,
+    day: u8,
+    cache: &InputCache,
+    downloader: Option<&dyn Downloader>,
+) -> Result<(), String> {
+    fs::create_dir_all(&cache.dir)
+        .map_err(|err| format!("cannot create {}: {err}", cache.dir.display()))?;
+    let input = crate_dir.join(input_file(day));
+    if input.exists() {
+        println!("Input {} already exists", input.display());
+        return Ok(());
+    }
+    let cached = match downloader {
+        Some(downloader) => cache.fetch(day, downloader),
+        None if cache.path(day).exists() => Ok(cache.path(day)),
+        None => {
+            println!(
+                "No AOC_SESSION set and day {day} is not cached, put the input in {} yourself",
+                input.display()
+            );
+            return Ok(());
+        }
+    };
+    match cached {
+        Ok(cached) => {
+            fs::copy(&cached, &input)
+                .map_err(|err| format!("cannot write {}: {err}", input.display()))?;
+            println!("Copied {} to {}", cached.display(), input.display());
+        }
+        Err(err) => println!("Cannot download the input of day {day}: {err}"),
+    }
+    Ok(())
+}
+
+// inputs come from --from DIR if given, else from the puzzle server when AOC_SESSION is set
+pub fn run(year: u16, crate_dir: &Path, day: u8, from: Option<&str>) -> Result<(), String> {
+    let root = default_cache_root().ok_or("Set AOC_CACHE_DIR or HOME to cache inputs")?;
+    let cache = InputCache::new(&root, year);
+    let downloader: Option<Box<dyn Downloader>> = match from {
+        Some(dir) => Some(Box::new(DirDownloader { dir: dir.into() })),
+        None => env::var("AOC_SESSION").ok().map(|session| {
+            Box::new(HttpDownloader {
+                base_url: env::var("AOC_URL").unwrap_or(DEFAULT_URL.to_string()),
+                session,
+            }) as Box<dyn Downloader>
+        }),
+    };
+    create_day(crate_dir, day)?;
+    fetch_input(crate_dir, day, &cache, downloader.as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+    use std::thread;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    // answers a single request with the given status and body, and hands back the request head
+    fn stand_in_server(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut head = String::new();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                head.push_str(&line);
+            }
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            head
+        });
+        (url, handle)
+    }
+
+    const MAIN_RS: &str = "mod d1;
+mod d10;
+mod d2;
+// mod d3;
+
+fn main() {
+    let registry = runner::build_registry(vec![
+        register(d1::Task),
+        register(d2::Task),
+        // register(d3::Task),
+        register(d10::Task),
+    ]);
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(
+        d1,
+        d2,
+        // d3,
+        d10,
+    );
+}
+";
+
+    #[test]
+    fn day_no_is_set() {
+        let template = "impl DayTask<i64> for Task {
+    fn day_no(&self) -> u8 {
+        todo!()
+    }
+
+    fn run_p1(&self) -> Result<i64> {
+        todo!()
+    }
+}";
+        let source = set_day_no(template, 21).unwrap();
+        assert!(source.contains("fn day_no(&self) -> u8 {\n        21\n    }"));
+        assert_eq!(source.matches("todo!()").count(), 1);
+    }
+
+    #[test]
+    fn placeholders_are_uncommented() {
+        let main_rs = register_day(MAIN_RS, 3).unwrap();
+        assert!(main_rs.contains("\nmod d3;\n"));
+        assert!(main_rs.contains("        register(d3::Task),\n"));
+        assert!(main_rs.contains("        d3,\n"));
+        assert!(!main_rs.contains("// "));
+        assert_eq!(register_day(&main_rs, 3).unwrap(), main_rs);
+    }
+
+    #[test]
+    fn missing_entries_are_inserted_in_order() {
+        let main_rs = register_day(MAIN_RS, 4).unwrap();
+        assert!(main_rs.contains("// mod d3;\nmod d4;\n\n"));
+        assert!(main_rs.contains(
+            "// register(d3::Task),\n        register(d4::Task),\n        register(d10::Task),"
+        ));
+        assert!(main_rs.contains("        // d3,\n        d4,\n        d10,"));
+        assert_eq!(register_day(&main_rs, 4).unwrap(), main_rs);
+        assert_eq!(register_day("fn main() {}", 4), None);
+    }
+
+    #[test]
+    fn inputs_are_cached_from_a_fixture_dir() {
+        let fixtures = temp_dir("fixtures");
+        fs::write(fixtures.join("d7.txt"), "1 2 3\n").unwrap();
+        let cache = InputCache::new(&temp_dir("cache"), 2024);
+        let path = cache
+            .fetch(
+                7,
+                &DirDownloader {
+                    dir: fixtures.clone(),
+                },
+            )
+            .unwrap();
+        assert_eq!(path, cache.path(7));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+
+        // a cached day is never downloaded again
+        fs::remove_file(fixtures.join("d7.txt")).unwrap();
+        assert!(cache.fetch(7, &DirDownloader { dir: fixtures }).is_ok());
+    }
+
+    #[test]
+    fn http_download_sends_the_session() {
+        let (base_url, server) = stand_in_server("200 OK", "abc\n");
+        let downloader = HttpDownloader {
+            base_url,
+            session: "53cr3t".to_string(),
+        };
+        assert_eq!(downloader.download(2024, 21).unwrap(), "abc\n");
+        let head = server.join().unwrap();
+        assert!(head.starts_with("GET /2024/day/21/input HTTP/1.1"));
+        assert!(head.contains("Cookie: session=53cr3t"));
+    }
+
+    #[test]
+    fn http_errors_are_reported() {
+        let (base_url, server) = stand_in_server("400 Bad Request", "log in first");
+        let cache = InputCache::new(&temp_dir("http-errors"), 2024);
+        let downloader = HttpDownloader {
+            base_url,
+            session: String::new(),
+        };
+        assert!(cache.fetch(22, &downloader).is_err());
+        assert!(!cache.path(22).exists());
+        server.join().unwrap();
+    }
+}
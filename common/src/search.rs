@@ -0,0 +1,264 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Add;
+
+// the cheapest way to a goal; `predecessors` is the DAG of the explored shortest paths,
+// with a single parent per state unless every tied path was asked for
+pub struct Found<S, C> {
+    pub cost: C,
+    pub goals: Vec<S>,
+    pub predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash, C> Found<S, C> {
+    // one shortest path, from a start state to the first goal reached
+    pub fn path(&self) -> Vec<S> {
+        let mut path = vec![self.goals[0].clone()];
+        while let Some(prev) = self.predecessors.get(path.last().unwrap()) {
+            path.push(prev[0].clone());
+        }
+        path.reverse();
+        path
+    }
+
+    // every state lying on any of the shortest paths that were kept
+    pub fn states(&self) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut stack = self.goals.clone();
+        while let Some(state) = stack.pop() {
+            if let Some(prev) = self.predecessors.get(&state) {
+                stack.extend(prev.iter().filter(|s| !seen.contains(*s)).cloned());
+            }
+            seen.insert(state);
+        }
+        seen
+    }
+}
+
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search(starts, successors, |_| C::default(), is_goal, false)
+}
+
+// the heuristic must never overestimate the remaining cost, or the result isn't the cheapest;
+// one that isn't also consistent can make states get expanded again once a cheaper way to
+// them turns up
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search(starts, successors, heuristic, is_goal, false)
+}
+
+// like dijkstra, but keeps every tied shortest path and every goal reached at the best cost
+pub fn dijkstra_all<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search(starts, successors, |_| C::default(), is_goal, true)
+}
+
+// heap entry ordered by estimated total cost only, reversed to make BinaryHeap a min-heap
+struct Entry<S, C> {
+    estimate: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+fn search<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+    keep_ties: bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut heap = BinaryHeap::new();
+    // cheapest known cost of every state pushed so far
+    let mut best: HashMap<S, C> = HashMap::new();
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    for state in starts {
+        if best.insert(state.clone(), C::default()).is_some() {
+            continue;
+        }
+        heap.push(Entry {
+            estimate: heuristic(&state),
+            cost: C::default(),
+            state,
+        });
+    }
+
+    let mut min_cost = None;
+    let mut goals = vec![];
+    while let Some(Entry {
+        estimate,
+        cost,
+        state,
+    }) = heap.pop()
+    {
+        if min_cost.is_some_and(|min| estimate > min) {
+            break;
+        }
+        // a cheaper way to this state was found after this entry was pushed; only ever pushing
+        // strictly cheaper costs means every other entry expands its state for the first time
+        // at that cost
+        if best[&state] < cost {
+            continue;
+        }
+        if is_goal(&state) {
+            min_cost = Some(cost);
+            goals.push(state);
+            if !keep_ties {
+                break;
+            }
+            continue;
+        }
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            match best.get(&next) {
+                Some(&prev_cost) if next_cost > prev_cost => {}
+                Some(&prev_cost) if next_cost == prev_cost => {
+                    if keep_ties {
+                        predecessors.entry(next).or_default().push(state.clone());
+                    }
+                }
+                _ => {
+                    best.insert(next.clone(), next_cost);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    heap.push(Entry {
+                        estimate: next_cost + heuristic(&next),
+                        cost: next_cost,
+                        state: next,
+                    });
+                }
+            }
+        }
+    }
+
+    Some(Found {
+        cost: min_cost?,
+        goals,
+        predecessors,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a diamond with two tied routes from 0 to 3, and a dearer direct edge
+    fn diamond(node: &u8) -> Vec<(u8, u32)> {
+        match node {
+            0 => vec![(1, 1), (2, 2), (3, 5)],
+            1 => vec![(3, 3)],
+            2 => vec![(3, 2)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn cheapest_path() {
+        let found = dijkstra([0], diamond, |&n| n == 3).unwrap();
+        assert_eq!(found.cost, 4);
+        assert_eq!(found.path().len(), 3);
+        assert_eq!(found.states().len(), 3);
+        assert!(dijkstra([1], diamond, |&n| n == 0).is_none());
+    }
+
+    #[test]
+    fn tied_paths() {
+        let found = dijkstra_all([0], diamond, |&n| n == 3).unwrap();
+        assert_eq!(found.cost, 4);
+        assert_eq!(found.states(), HashSet::from([0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn astar_on_a_grid() {
+        let walls = [(1, 0), (1, 1), (1, 2), (3, 4), (3, 3), (3, 2)];
+        let successors = |&(x, y): &(i32, i32)| {
+            [(1, 0), (-1, 0), (0, 1), (0, -1)]
+                .into_iter()
+                .map(move |(dx, dy)| (x + dx, y + dy))
+                .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y))
+                .filter(|p| !walls.contains(p))
+                .map(|p| (p, 1))
+        };
+        let goal = (4, 0);
+        let manhattan = |&(x, y): &(i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs();
+        let found = astar([(0, 0)], successors, manhattan, |&p| p == goal).unwrap();
+        assert_eq!(found.cost, 10);
+        assert_eq!(found.path().first(), Some(&(0, 0)));
+        assert_eq!(found.path().last(), Some(&goal));
+        assert_eq!(
+            dijkstra([(0, 0)], successors, |&p| p == goal).unwrap().cost,
+            found.cost
+        );
+    }
+
+    #[test]
+    fn astar_with_an_inconsistent_heuristic() {
+        // 0 -> 1 -> 3 -> 4 costs 5, 0 -> 2 -> 3 -> 4 costs 6; the heuristic never overestimates
+        // but makes 3 get expanded through 2 first, and again once the way through 1 shows up
+        let successors = |node: &u8| match node {
+            0 => vec![(1, 1), (2, 1)],
+            1 => vec![(3, 1)],
+            2 => vec![(3, 2)],
+            3 => vec![(4, 3)],
+            _ => vec![],
+        };
+        let heuristic = |node: &u8| match node {
+            1 => 4,
+            2 | 3 => 1,
+            _ => 0,
+        };
+        let found = astar([0], successors, heuristic, |&n| n == 4).unwrap();
+        assert_eq!(found.cost, 5);
+        assert_eq!(found.path(), vec![0, 1, 3, 4]);
+    }
+}
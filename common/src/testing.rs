@@ -0,0 +1,80 @@
+use std::fmt::{Debug, Display};
+use std::path::Path;
+
+use crate::DayTask;
+
+// Turns every listed day module into plain #[test] functions: one running all the examples
+// of each part, named like `d5::part2_examples`, and one for the confirmed answer of each
+// part. Invoke it from a module one level below the crate root:
+//
+//     #[cfg(test)]
+//     mod tests {
+//         aoc_common::day_tests!(d1, d2, d3);
+//     }
+#[macro_export]
+macro_rules! day_tests {
+    ($($day:ident),* $(,)?) => {
+        $(
+            mod $day {
+                #[test]
+                fn part1_examples() {
+                    $crate::testing::check_examples(&super::super::$day::Task, 1);
+                }
+
+                #[test]
+                fn part2_examples() {
+                    $crate::testing::check_examples(&super::super::$day::Task, 2);
+                }
+
+                #[test]
+                fn part1_answer() {
+                    $crate::testing::check_answer(
+                        &super::super::$day::Task,
+                        1,
+                        env!("CARGO_MANIFEST_DIR"),
+                    );
+                }
+
+                #[test]
+                fn part2_answer() {
+                    $crate::testing::check_answer(
+                        &super::super::$day::Task,
+                        2,
+                        env!("CARGO_MANIFEST_DIR"),
+                    );
+                }
+            }
+        )*
+    };
+}
+
+// a part with examples must be implemented and get every one of them right
+pub fn check_examples<T, D>(task: &D, part: u8)
+where
+    T: Debug + Display + Eq,
+    D: DayTask<T>,
+{
+    if let Err(err) = task.check_examples(part) {
+        panic!("Day {}: {err}", task.day_no());
+    }
+}
+
+// Days without a confirmed answer for the part are not run at all
+pub fn check_answer<T, D>(task: &D, part: u8, input_dir: &str)
+where
+    T: Debug + Display + Eq,
+    D: DayTask<T>,
+{
+    if task.get_result(part).is_none() {
+        return;
+    }
+    let day = task.day_no();
+    let input = Path::new(input_dir).join(format!("d{day}.txt"));
+    let result = task
+        .read_lines(&input.to_string_lossy())
+        .and_then(|lines| task.solve(part, &lines, false))
+        .and_then(|answer| task.check_answer(part, &answer));
+    if let Err(err) = result {
+        panic!("Day {day}: {err}");
+    }
+}